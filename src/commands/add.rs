@@ -0,0 +1,308 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id, print_data};
+use super::{quality_profile_id, tag_ids};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    root_folder_args(SubCommand::with_name("add")
+        .about("Add the given movie. If it's already in the library it's printed and the exit code is 3.")
+        .arg(Arg::with_name("file")
+             .help("Path to file containing search result json")
+             .long("file")
+             .short("f")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("data")
+             .help("Raw JSON data of search result")
+             .long("data")
+             .short("d")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("tmdb")
+             .help("TMDb ID of the movie to look up and add, instead of a search result")
+             .long("tmdb")
+             .takes_value(true)
+             .conflicts_with_all(&["file", "data", "imdb"])
+             )
+        .arg(Arg::with_name("imdb")
+             .help("IMDb ID (eg: tt0133093) of the movie to look up and add, instead of a search result")
+             .long("imdb")
+             .takes_value(true)
+             .conflicts_with_all(&["file", "data"])
+             )
+        .arg(Arg::with_name("title")
+             .help("Title of the movie to search for and add, if a result matches it confidently enough")
+             .long("title")
+             .takes_value(true)
+             .conflicts_with_all(&["file", "data", "tmdb", "imdb"])
+             )
+        .arg(Arg::with_name("year")
+             .help("Release year of the movie given with --title")
+             .long("year")
+             .takes_value(true)
+             .requires("title")
+             )
+        .arg(Arg::with_name("year-tolerance")
+             .help("How many years --year may be off by and still count as a close match")
+             .long("year-tolerance")
+             .takes_value(true)
+             .default_value("1")
+             )
+        .arg(Arg::with_name("min-confidence")
+             .help("How confident (0 to 1) the best match for --title must be to add it")
+             .long("min-confidence")
+             .takes_value(true)
+             .default_value("0.9")
+             )
+        .arg(Arg::with_name("quality-profile")
+             .help("Name or ID of the quality profile to add the movie with (default: the search result's, or the first profile for --tmdb/--imdb/--title lookups)")
+             .long("quality-profile")
+             .short("q")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("monitor")
+             .help("What to monitor once the movie is added (default: `movieOnly`)")
+             .long("monitor")
+             .takes_value(true)
+             .possible_values(radarr::MonitorMode::VALUES)
+             )
+        .arg(Arg::with_name("unmonitored")
+             .help("Add the movie without monitoring it. Same as `--monitor none`.")
+             .long("unmonitored")
+             .conflicts_with("monitor")
+             )
+        .arg(Arg::with_name("minimum-availability")
+             .help("When the movie is considered available for download")
+             .long("minimum-availability")
+             .takes_value(true)
+             .possible_values(radarr::MinimumAvailability::SELECTABLE)
+             )
+        .arg(Arg::with_name("tag")
+             .help("Tag the movie with this. Can be given more than once, and tags that don't exist are created.")
+             .long("tag")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+        .arg(Arg::with_name("path")
+             .help("Full path of the movie's folder, instead of a folder named after it in a root folder")
+             .long("path")
+             .takes_value(true)
+             .conflicts_with_all(&["root-folder", "root-folder-strategy", "min-free-gb", "root-folder-rules"])
+             )
+        .arg(Arg::with_name("exists-ok")
+             .help("Exit successfully if the movie is already in the library, instead of with exit code 3")
+             .long("exists-ok")
+             )
+        .arg(Arg::with_name("no-search")
+             .help("Do not search for the movie. Just add it.")
+             .long("no-search")
+             .takes_value(false)
+             ))
+}
+
+// the flags for picking a root folder, shared with the other commands that add movies
+pub fn root_folder_args<'a, 'b>(command: App<'a, 'b>) -> App<'a, 'b> {
+    command
+        .arg(Arg::with_name("root-folder")
+             .help("Root folder to download movie. Must be one of the available root folders.")
+             .long("root-folder")
+             .short("r")
+             .takes_value(true)
+             .conflicts_with_all(&["root-folder-strategy", "min-free-gb", "root-folder-rules"])
+             )
+        .arg(Arg::with_name("root-folder-strategy")
+             .help("How to pick a root folder when --root-folder isn't given (default: `first`)")
+             .long("root-folder-strategy")
+             .takes_value(true)
+             .possible_values(&["first", "most-free"])
+             .conflicts_with_all(&["min-free-gb", "root-folder-rules"])
+             )
+        .arg(Arg::with_name("min-free-gb")
+             .help("Pick the first root folder with at least this many GB free")
+             .long("min-free-gb")
+             .takes_value(true)
+             .conflicts_with("root-folder-rules")
+             )
+        .arg(Arg::with_name("root-folder-rules")
+             .help("Pick the root folder using the genre/tag rules in this json file")
+             .long("root-folder-rules")
+             .takes_value(true)
+             )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, add_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let result = if let Some(tmdb_id) = add_matches.value_of("tmdb") {
+        *client.lookup_by_tmdb(parse_id(tmdb_id, "tmdb")?)?.data
+    } else if let Some(imdb_id) = add_matches.value_of("imdb") {
+        *client.lookup_by_imdb(imdb_id)?.data
+    } else if let Some(title) = add_matches.value_of("title") {
+        match_title(client, title, add_matches)?
+    } else {
+        // parse the payload from wherever we're getting it.
+        let data_source = radarr::DataSource::from_matches(add_matches)
+            .ok_or("Give either --file or --data, not both")?;
+        let data = data_source.read()?;
+        serde_json::from_str(&data)?
+    };
+
+    // adding a movie twice is a no-op: hand back the one that's already there
+    if let Some(movie) = client.find_movie_by_tmdb(result.tmdb_id)? {
+        print_data(matches, &movie);
+
+        if add_matches.is_present("exists-ok") {
+            return Ok(());
+        }
+
+        return Err(Box::new(radarr::error::MovieExists::new(&movie.title, result.tmdb_id, Some(movie.id))));
+    }
+
+    let mut payload = radarr::AddMoviePayload::from_movie_response(&result)?
+        .with_search_for_movie(!add_matches.is_present("no-search"));
+
+    if let Some(quality_profile) = add_matches.value_of("quality-profile") {
+        payload = payload.with_quality_profile_id(quality_profile_id(client, quality_profile)?);
+    } else if payload.quality_profile_id == 0 {
+        // lookups of movies that aren't tracked come without a profile
+        payload = payload.with_quality_profile_id(first_quality_profile_id(client)?);
+    }
+
+    if add_matches.is_present("unmonitored") {
+        payload = payload.with_monitored(false).with_monitor(radarr::MonitorMode::None);
+    } else if let Some(monitor) = add_matches.value_of("monitor") {
+        let monitor: radarr::MonitorMode = monitor.parse()?;
+        payload = payload.with_monitored(monitor != radarr::MonitorMode::None).with_monitor(monitor);
+    }
+
+    if let Some(minimum_availability) = add_matches.value_of("minimum-availability") {
+        payload = payload.with_minimum_availability(minimum_availability.parse()?);
+    }
+
+    if let Some(labels) = add_matches.values_of("tag") {
+        payload = payload.with_tags(tag_ids(client, labels)?);
+    }
+
+    payload = match add_matches.value_of("path") {
+        Some(path) => payload.with_path(path),
+        None => {
+            let strategy = root_folder_strategy_from_matches(add_matches)?;
+            let root_folders = client.root_folder()?;
+            let root_folder = strategy.select(&root_folders.data, &result.genres, &tag_labels(add_matches))?;
+
+            payload.with_root_folder_path(&root_folder.path)
+        },
+    };
+
+    let resp = client.add_movie(&payload)?;
+    handle_resp(matches, resp)
+}
+
+// the profile with the lowest ID, which on a fresh install is Radarr's own `Any`
+fn first_quality_profile_id(client: &radarr::Client) -> Result<u32, Box<dyn Error>> {
+    client.quality_profiles()?.data.iter()
+        .filter_map(|profile| profile.id)
+        .min()
+        .ok_or_else(|| "Radarr has no quality profiles. Create one or give --quality-profile".into())
+}
+
+// search for the title and pick the result that matches it best, refusing to guess when no
+// result is a confident, unambiguous match
+fn match_title(client: &radarr::Client, title: &str, add_matches: &ArgMatches) -> Result<radarr::SearchResult, Box<dyn Error>> {
+    let year = match add_matches.value_of("year") {
+        Some(year) => Some(parse_id(year, "year")?),
+        None => None,
+    };
+    let year_tolerance = parse_id(add_matches.value_of("year-tolerance").unwrap(), "year-tolerance")?;
+    let min_confidence = add_matches.value_of("min-confidence").unwrap();
+    let min_confidence = min_confidence.parse::<f64>()
+        .map_err(|_| format!("Failed to parse min-confidence: `{}`", min_confidence))?;
+
+    let query = radarr::TitleQuery::new(title, year).with_year_tolerance(year_tolerance);
+
+    match query.pick(*client.search(title)?.data, min_confidence) {
+        radarr::TitlePick::Matched(result, confidence) => {
+            eprintln!("matched {} ({}) with confidence {:.2}", result.title, result.year, confidence);
+
+            Ok(*result)
+        },
+        radarr::TitlePick::Ambiguous(candidates) => {
+            eprintln!("No confident match for `{}`. Closest results:", title);
+            print_candidates(&candidates);
+
+            Err("Use --tmdb or --imdb to pick one, or lower --min-confidence".into())
+        },
+        radarr::TitlePick::NotFound => Err(format!("No results for `{}`", title).into()),
+    }
+}
+
+pub fn print_candidates(candidates: &[(radarr::SearchResult, f64)]) {
+    for (result, confidence) in candidates.iter().take(5) {
+        eprintln!("  {:.2}  {} ({})  tmdb {}", confidence, result.title, result.year, result.tmdb_id);
+    }
+}
+
+// the `--tag` labels as given, which is what root folder rules match tags against. Lookups can't be
+// used for this: they have no tags for movies that aren't in the library yet.
+pub fn tag_labels(matches: &ArgMatches) -> Vec<String> {
+    matches.values_of("tag")
+        .map(|labels| labels.map(String::from).collect())
+        .unwrap_or_default()
+}
+
+pub fn root_folder_strategy_from_matches(matches: &ArgMatches) -> Result<radarr::RootFolderStrategy, Box<dyn Error>> {
+    if let Some(path) = matches.value_of("root-folder") {
+        return Ok(radarr::RootFolderStrategy::Explicit(path.to_string()));
+    }
+
+    if let Some(gb) = matches.value_of("min-free-gb") {
+        let gb = gb.parse::<u64>()
+            .map_err(|_| format!("Failed to parse min-free-gb: `{}`", gb))?;
+
+        return Ok(radarr::RootFolderStrategy::MinimumFreeSpace(gb));
+    }
+
+    if let Some(file_path) = matches.value_of("root-folder-rules") {
+        let rules = radarr::RootFolderRules::from_file(file_path)?;
+
+        return Ok(radarr::RootFolderStrategy::Rules(rules));
+    }
+
+    match matches.value_of("root-folder-strategy") {
+        Some("most-free") => Ok(radarr::RootFolderStrategy::MostFreeSpace),
+        _ => Ok(radarr::RootFolderStrategy::First),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn root_folder_rules_match_the_tags_given_to_add() {
+        let rules_path = std::env::temp_dir().join(format!("radarr-add-rules-{}.json", std::process::id()));
+        let rules = json!({
+            "rules": [{ "tag": "kids", "path": "/storage/Kids/" }],
+            "default": "/storage/Movies/",
+        });
+        std::fs::write(&rules_path, rules.to_string()).unwrap();
+
+        let add_matches = subcommand().get_matches_from(vec![
+            "add", "--tmdb", "862", "--tag", "Kids", "--root-folder-rules", rules_path.to_str().unwrap(),
+        ]);
+        let strategy = root_folder_strategy_from_matches(&add_matches).unwrap();
+        std::fs::remove_file(&rules_path).unwrap();
+
+        let root_folders: Vec<radarr::RootFolderResponse> = serde_json::from_value(json!([
+            { "freeSpace": 0, "id": 1, "path": "/storage/Movies/" },
+            { "freeSpace": 0, "id": 2, "path": "/storage/Kids/" },
+        ])).unwrap();
+
+        let genres = vec![String::from("Animation")];
+        let root_folder = strategy.select(&root_folders, &genres, &tag_labels(&add_matches)).unwrap();
+
+        assert_eq!(root_folder.path, "/storage/Kids/");
+    }
+}
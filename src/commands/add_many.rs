@@ -0,0 +1,310 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+use serde::Serialize;
+
+use crate::{parse_id, print_data};
+use super::add::{root_folder_args, root_folder_strategy_from_matches, tag_labels};
+use super::{quality_profile_id, tag_ids};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    bulk_add_args(SubCommand::with_name("add-many")
+        .about("Add every movie in a list of titles, TMDb IDs or IMDb IDs, or in a watchlist export")
+        .arg(Arg::with_name("file")
+             .help("The list: one movie per line as `title[,year]`, a TMDb ID or an IMDb ID. Csv and tab separated rows both work. Use `-` for stdin.")
+             .required(true)
+             )
+        .arg(Arg::with_name("format")
+             .help("What the file is: a plain list, or a Letterboxd, IMDb or Trakt (json) watchlist export")
+             .long("format")
+             .takes_value(true)
+             .possible_values(&["list", "letterboxd", "imdb", "trakt"])
+             .default_value("list")
+             ))
+}
+
+// the flags for how to match and add movies, shared with the other commands that add many at once
+pub fn bulk_add_args<'a, 'b>(command: App<'a, 'b>) -> App<'a, 'b> {
+    root_folder_args(command)
+        .arg(Arg::with_name("quality-profile")
+             .help("Name or ID of the quality profile to add movies with")
+             .long("quality-profile")
+             .short("q")
+             .takes_value(true)
+             .required(true)
+             )
+        .arg(Arg::with_name("tag")
+             .help("Tag every added movie with this. Can be given more than once, and tags that don't exist are created.")
+             .long("tag")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+        .arg(Arg::with_name("monitor")
+             .help("What to monitor once a movie is added")
+             .long("monitor")
+             .takes_value(true)
+             .possible_values(radarr::MonitorMode::VALUES)
+             .default_value("movieOnly")
+             )
+        .arg(Arg::with_name("no-search")
+             .help("Do not search for the movies. Just add them.")
+             .long("no-search")
+             )
+        .arg(Arg::with_name("min-confidence")
+             .help("How confident (0 to 1) the best match for a title must be to add it")
+             .long("min-confidence")
+             .takes_value(true)
+             .default_value("0.9")
+             )
+        .arg(Arg::with_name("year-tolerance")
+             .help("How many years a row's year may be off by and still count as a close match")
+             .long("year-tolerance")
+             .takes_value(true)
+             .default_value("1")
+             )
+        .arg(Arg::with_name("dry-run")
+             .help("Resolve every row and report what would happen, without adding anything")
+             .long("dry-run")
+             )
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RowStatus {
+    Added,
+    WouldAdd,
+    AlreadyPresent,
+    Ambiguous,
+    NotFound,
+    Failed,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RowResult {
+    pub line: usize,
+    pub input: String,
+    pub status: RowStatus,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<u32>,
+
+    #[serde(rename = "tmdbId", skip_serializing_if = "Option::is_none")]
+    pub tmdb_id: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+// how to add each movie, shared by every row
+pub struct BulkAddOptions {
+    pub quality_profile_id: u32,
+    pub root_folder_strategy: radarr::RootFolderStrategy,
+    pub tags: Vec<u32>,
+    pub tag_labels: Vec<String>,
+    pub monitor: radarr::MonitorMode,
+    pub search: bool,
+    pub min_confidence: f64,
+    pub year_tolerance: u32,
+    pub dry_run: bool,
+}
+
+enum Resolved {
+    Found(Box<radarr::SearchResult>),
+    Ambiguous(String),
+    NotFound(String),
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, add_many_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let data_source = radarr::DataSource::from(add_many_matches.value_of("file"), None).unwrap();
+    let format: radarr::ListFormat = add_many_matches.value_of("format").unwrap().parse()?;
+    let entries = radarr::parse_watchlist(format, &data_source.read()?)?;
+
+    let options = options_from_matches(client, add_many_matches)?;
+    let results = add_all(client, &entries, &options)?;
+
+    report(matches, &results);
+
+    if results.iter().any(|result| result.status == RowStatus::Failed) {
+        return Err("Some movies could not be added".into());
+    }
+
+    Ok(())
+}
+
+pub fn options_from_matches(client: &radarr::Client, matches: &ArgMatches) -> Result<BulkAddOptions, Box<dyn Error>> {
+    let min_confidence = matches.value_of("min-confidence").unwrap();
+    let tags = match matches.values_of("tag") {
+        Some(labels) if !matches.is_present("dry-run") => tag_ids(client, labels)?,
+        _ => vec![],
+    };
+
+    Ok(BulkAddOptions {
+        quality_profile_id: quality_profile_id(client, matches.value_of("quality-profile").unwrap())?,
+        root_folder_strategy: root_folder_strategy_from_matches(matches)?,
+        tags,
+        tag_labels: tag_labels(matches),
+        monitor: matches.value_of("monitor").unwrap().parse()?,
+        search: !matches.is_present("no-search"),
+        min_confidence: min_confidence.parse::<f64>()
+            .map_err(|_| format!("Failed to parse min-confidence: `{}`", min_confidence))?,
+        year_tolerance: parse_id(matches.value_of("year-tolerance").unwrap(), "year-tolerance")?,
+        dry_run: matches.is_present("dry-run"),
+    })
+}
+
+// resolve and add every entry in order, skipping anything already in the library (or added by an
+// earlier row). Failures to add are recorded rather than stopping the run.
+pub fn add_all(client: &radarr::Client, entries: &[radarr::MovieListEntry], options: &BulkAddOptions) -> Result<Vec<RowResult>, Box<dyn Error>> {
+    let mut existing: HashSet<u32> = client.list_movies()?.data.iter()
+        .filter_map(|movie| movie.tmdb_id)
+        .collect();
+    let root_folders = client.root_folder()?;
+
+    let mut results = vec![];
+
+    for entry in entries {
+        let mut row = RowResult {
+            line: entry.line,
+            input: entry.movie.to_string(),
+            status: RowStatus::NotFound,
+            title: None,
+            year: None,
+            tmdb_id: None,
+            message: None,
+        };
+
+        let result = match resolve(client, &entry.movie, options) {
+            Ok(Resolved::Found(result)) => result,
+            Ok(Resolved::Ambiguous(message)) => {
+                row.status = RowStatus::Ambiguous;
+                row.message = Some(message);
+                results.push(row);
+                continue;
+            },
+            Ok(Resolved::NotFound(message)) => {
+                row.message = Some(message);
+                results.push(row);
+                continue;
+            },
+            // a lookup of an id Radarr doesn't know is a 404. Anything else (network, auth, server
+            // errors) is a failure, not a missing movie.
+            Err(err) => {
+                let not_found = err.downcast_ref::<radarr::error::ApiError>().is_some_and(|err| err.status == 404);
+
+                if !not_found {
+                    row.status = RowStatus::Failed;
+                }
+
+                row.message = Some(err.to_string());
+                results.push(row);
+                continue;
+            },
+        };
+
+        row.title = Some(result.title.to_owned());
+        row.year = Some(result.year);
+        row.tmdb_id = Some(result.tmdb_id);
+
+        if existing.contains(&result.tmdb_id) || result.is_existing() {
+            row.status = RowStatus::AlreadyPresent;
+        } else if options.dry_run {
+            row.status = RowStatus::WouldAdd;
+            existing.insert(result.tmdb_id);
+        } else {
+            match add(client, &result, &root_folders.data, options) {
+                Ok(()) => {
+                    row.status = RowStatus::Added;
+                    existing.insert(result.tmdb_id);
+                },
+                Err(err) if err.downcast_ref::<radarr::error::MovieExists>().is_some() => {
+                    row.status = RowStatus::AlreadyPresent;
+                },
+                Err(err) => {
+                    row.status = RowStatus::Failed;
+                    row.message = Some(err.to_string());
+                },
+            }
+        }
+
+        results.push(row);
+    }
+
+    Ok(results)
+}
+
+fn resolve(client: &radarr::Client, movie: &radarr::MovieRef, options: &BulkAddOptions) -> Result<Resolved, Box<dyn Error>> {
+    let resolved = match movie {
+        radarr::MovieRef::Tmdb(tmdb_id) => Resolved::Found(client.lookup_by_tmdb(*tmdb_id)?.data),
+        radarr::MovieRef::Imdb(imdb_id) => Resolved::Found(client.lookup_by_imdb(imdb_id)?.data),
+        radarr::MovieRef::Title { title, year } => {
+            let query = radarr::TitleQuery::new(title, *year).with_year_tolerance(options.year_tolerance);
+
+            match query.pick(*client.search(title)?.data, options.min_confidence) {
+                radarr::TitlePick::Matched(result, _) => Resolved::Found(result),
+                radarr::TitlePick::Ambiguous(candidates) => {
+                    let closest: Vec<String> = candidates.iter().take(3)
+                        .map(|(result, confidence)| format!("{} ({}) {:.2}", result.title, result.year, confidence))
+                        .collect();
+
+                    Resolved::Ambiguous(format!("closest: {}", closest.join(", ")))
+                },
+                radarr::TitlePick::NotFound => Resolved::NotFound(String::from("no search results")),
+            }
+        },
+    };
+
+    Ok(resolved)
+}
+
+fn add(client: &radarr::Client, result: &radarr::SearchResult, root_folders: &[radarr::RootFolderResponse], options: &BulkAddOptions) -> Result<(), Box<dyn Error>> {
+    let mut payload = radarr::AddMoviePayload::from_movie_response(result)?;
+    let root_folder = options.root_folder_strategy.select(root_folders, &result.genres, &options.tag_labels)?;
+
+    payload.set_quality_profile_id(options.quality_profile_id);
+    payload.set_root_folder_path(&root_folder.path);
+    payload.set_tags(options.tags.to_vec());
+    payload.set_monitor(options.monitor.clone());
+    payload.set_monitored(options.monitor != radarr::MonitorMode::None);
+    payload.set_search_for_movie(options.search);
+
+    client.add_movie(&payload)?;
+
+    Ok(())
+}
+
+pub fn report(matches: &ArgMatches, results: &[RowResult]) {
+    if matches.is_present("json") {
+        print_data(matches, &results);
+        return;
+    }
+
+    for result in results.iter() {
+        let status = serde_json::to_value(result.status).ok()
+            .and_then(|status| status.as_str().map(String::from))
+            .unwrap_or_default();
+
+        let found = match (&result.title, result.year) {
+            (Some(title), Some(year)) => format!(" => {} ({})", title, year),
+            _ => String::new(),
+        };
+
+        let message = result.message.as_ref().map(|message| format!(": {}", message)).unwrap_or_default();
+
+        println!("{:>5}  {:15}  {}{}{}", result.line, status, result.input, found, message);
+    }
+
+    let count = |status| results.iter().filter(|result| result.status == status).count();
+
+    eprintln!("{} added, {} would be added, {} already present, {} ambiguous, {} not found, {} failed",
+              count(RowStatus::Added),
+              count(RowStatus::WouldAdd),
+              count(RowStatus::AlreadyPresent),
+              count(RowStatus::Ambiguous),
+              count(RowStatus::NotFound),
+              count(RowStatus::Failed));
+}
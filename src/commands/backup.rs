@@ -0,0 +1,202 @@
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id, print_data};
+use super::{confirm, wait_for_command};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("backup")
+        .about("Manage Radarr backups")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List the backups on the server")
+                    )
+        .subcommand(SubCommand::with_name("create")
+                    .about("Create a new backup on the server")
+                    .arg(Arg::with_name("wait")
+                         .help("Wait for the backup to finish")
+                         .long("wait")
+                         .short("w")
+                         )
+                    )
+        .subcommand(SubCommand::with_name("download")
+                    .about("Download a backup to a local directory")
+                    .arg(Arg::with_name("backup_id")
+                         .help("The ID of the backup to download (default: the most recent)")
+                         )
+                    .arg(Arg::with_name("output")
+                         .help("The directory to download into (default: `.`)")
+                         .long("output")
+                         .short("o")
+                         .takes_value(true)
+                         )
+                    .arg(Arg::with_name("keep")
+                         .help("After downloading, delete all but this many of the newest backups in the output directory")
+                         .long("keep")
+                         .short("k")
+                         .takes_value(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("restore")
+                    .about("Restore a backup. Radarr restarts afterwards.")
+                    .arg(Arg::with_name("backup_id")
+                         .help("The ID of a backup on the server to restore")
+                         .required_unless("file")
+                         .conflicts_with("file")
+                         )
+                    .arg(Arg::with_name("file")
+                         .help("Upload and restore this local backup zip")
+                         .long("file")
+                         .short("f")
+                         .takes_value(true)
+                         )
+                    .arg(Arg::with_name("yes")
+                         .help("Don't ask for confirmation before restoring")
+                         .long("yes")
+                         .short("y")
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, backup_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if backup_matches.subcommand_matches("list").is_some() {
+        handle_resp(matches, client.backups()?)?;
+    } else if let Some(create_matches) = backup_matches.subcommand_matches("create") {
+        let resp = client.create_backup()?;
+
+        if create_matches.is_present("wait") {
+            let command = wait_for_command(client, resp.data.id)?;
+            print_data(matches, &command);
+        } else {
+            handle_resp(matches, resp)?;
+        }
+    } else if let Some(download_matches) = backup_matches.subcommand_matches("download") {
+        download(client, matches, download_matches)?;
+    } else if let Some(restore_matches) = backup_matches.subcommand_matches("restore") {
+        let target = restore_matches.value_of("file")
+            .map(String::from)
+            .unwrap_or_else(|| format!("backup {}", restore_matches.value_of("backup_id").unwrap()));
+
+        let prompt = format!("Restore {}? Radarr will restart.", target);
+        if !restore_matches.is_present("yes") && !confirm(&prompt)? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+
+        if let Some(file_path) = restore_matches.value_of("file") {
+            handle_resp(matches, client.restore_backup_upload(Path::new(file_path))?)?;
+        } else {
+            let backup_id = parse_id(restore_matches.value_of("backup_id").unwrap(), "backup_id")?;
+            handle_resp(matches, client.restore_backup(backup_id)?)?;
+        }
+    } else {
+        return Err("Missing backup subcommand. See `radarr backup --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn download(client: &radarr::Client, matches: &ArgMatches, download_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let output = Path::new(download_matches.value_of("output").unwrap_or("."));
+    let keep = match download_matches.value_of("keep") {
+        Some(keep) => Some(keep.parse::<usize>().map_err(|_| format!("Failed to parse keep: `{}`", keep))?),
+        None => None,
+    };
+
+    let backups = client.backups()?;
+    let backup = match download_matches.value_of("backup_id") {
+        Some(backup_id) => {
+            let backup_id = parse_id(backup_id, "backup_id")?;
+            backups.data.iter().find(|backup| backup.id == backup_id)
+        },
+        None => backups.data.iter().max_by_key(|backup| backup.time),
+    }.ok_or("No matching backup found on the server")?;
+
+    fs::create_dir_all(output)?;
+    let file_path = output.join(&backup.name);
+
+    // download next to the final file and only rename it into place once complete, so a failed
+    // download never leaves a partial zip around for `--keep` to count as a backup
+    let partial_path = output.join(format!(".{}.part", backup.name));
+    let resp = {
+        let mut file = File::create(&partial_path)?;
+        client.download_backup(backup, &mut file)
+    };
+
+    if resp.is_err() {
+        fs::remove_file(&partial_path)?;
+    }
+
+    let resp = resp?;
+    fs::rename(&partial_path, &file_path)?;
+
+    if matches.is_present("json") {
+        print_data(matches, &backup);
+    } else {
+        println!("Downloaded {} ({} bytes)", file_path.display(), resp.data);
+    }
+
+    if let Some(keep) = keep {
+        for pruned in prune_backups(output, keep)? {
+            eprintln!("Pruned {}", pruned.display());
+        }
+    }
+
+    Ok(())
+}
+
+// delete all but the `keep` most recently modified backup zips in `dir`. Returns what was deleted.
+fn prune_backups(dir: &Path, keep: usize) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut backups = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if name.starts_with("radarr_backup_") && name.ends_with(".zip") {
+            backups.push((entry.metadata()?.modified()?, entry.path()));
+        }
+    }
+
+    // newest first
+    backups.sort_by_key(|backup| Reverse(backup.0));
+
+    let mut pruned = vec![];
+    for (_, path) in backups.into_iter().skip(keep) {
+        fs::remove_file(&path)?;
+        pruned.push(path);
+    }
+
+    Ok(pruned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn prune_backups_keeps_newest() {
+        let dir = std::env::temp_dir().join(format!("radarr-prune-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let now = SystemTime::now();
+        for (i, name) in ["radarr_backup_a.zip", "radarr_backup_b.zip", "radarr_backup_c.zip", "notes.txt"].iter().enumerate() {
+            let file = File::create(dir.join(name)).unwrap();
+            file.set_modified(now - Duration::from_secs(60 * (i as u64 + 1))).unwrap();
+        }
+
+        let pruned = prune_backups(&dir, 2).unwrap();
+        let remaining: Vec<bool> = ["radarr_backup_a.zip", "radarr_backup_b.zip", "radarr_backup_c.zip", "notes.txt"].iter()
+            .map(|name| dir.join(name).exists())
+            .collect();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(pruned, vec![dir.join("radarr_backup_c.zip")]);
+        assert_eq!(remaining, vec![true, true, false, true]);
+    }
+}
@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use radarr::ShowtimeSource;
+use super::add_many::{add_all, bulk_add_args, options_from_matches, report, RowStatus};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    bulk_add_args(SubCommand::with_name("cinema-sync")
+        .about("Add the films showing at cinemas, as listed in a cinema feed, that aren't tracked yet")
+        .arg(Arg::with_name("feed")
+             .help("Path or url of the cinema feed json")
+             .required(true)
+             )
+        .arg(Arg::with_name("show-type")
+             .help("Only add films shown as part of this series, eg: `Terror Tuesday`. Can be given more than once.")
+             .long("show-type")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+        .arg(Arg::with_name("cinema")
+             .help("Only add films showing at this cinema. Can be given more than once.")
+             .long("cinema")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             ))
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, sync_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let source = radarr::CinemaFeed::new(sync_matches.value_of("feed").unwrap());
+
+    let filter = radarr::ShowingFilter {
+        show_types: values(sync_matches, "show-type"),
+        cinemas: values(sync_matches, "cinema"),
+    };
+
+    let showings = filter.apply(source.showings()?);
+
+    let entries: Vec<radarr::MovieListEntry> = showings.into_iter()
+        .enumerate()
+        .map(|(index, showing)| radarr::MovieListEntry {
+            line: index + 1,
+            movie: match showing.tmdb_id {
+                Some(tmdb_id) => radarr::MovieRef::Tmdb(tmdb_id),
+                None => radarr::MovieRef::Title { title: showing.title, year: showing.year },
+            },
+        })
+        .collect();
+
+    let options = options_from_matches(client, sync_matches)?;
+    let results = add_all(client, &entries, &options)?;
+
+    report(matches, &results);
+
+    if results.iter().any(|result| result.status == RowStatus::Failed) {
+        return Err("Some films could not be added".into());
+    }
+
+    Ok(())
+}
+
+fn values(matches: &ArgMatches, name: &str) -> Vec<String> {
+    matches.values_of(name)
+        .map(|values| values.map(String::from).collect())
+        .unwrap_or_default()
+}
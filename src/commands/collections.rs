@@ -0,0 +1,248 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+use serde_json::json;
+
+use crate::{handle_resp, parse_id, print_data};
+use super::{confirm, quality_profile_id};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("collections")
+        .about("Manage movie collections and find the films in them that aren't in the library")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List all collections with how many of their films are missing")
+                    )
+        .subcommand(SubCommand::with_name("show")
+                    .about("Show the collection with the given ID")
+                    .arg(Arg::with_name("collection_id")
+                         .help("The ID of the collection")
+                         .required(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("set")
+                    .about("Change how Radarr handles a collection")
+                    .arg(Arg::with_name("collection_id")
+                         .help("The ID of the collection")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("monitored")
+                         .help("Whether Radarr adds new films in the collection by itself")
+                         .long("monitored")
+                         .takes_value(true)
+                         .possible_values(&["true", "false"])
+                         )
+                    .arg(Arg::with_name("search-on-add")
+                         .help("Whether films Radarr adds from the collection are searched for")
+                         .long("search-on-add")
+                         .takes_value(true)
+                         .possible_values(&["true", "false"])
+                         )
+                    .arg(Arg::with_name("quality-profile")
+                         .help("Name or ID of the quality profile for films added from the collection")
+                         .long("quality-profile")
+                         .takes_value(true)
+                         )
+                    .arg(Arg::with_name("root-folder")
+                         .help("The root folder for films added from the collection")
+                         .long("root-folder")
+                         .takes_value(true)
+                         )
+                    .arg(Arg::with_name("minimum-availability")
+                         .help("When films added from the collection are considered available")
+                         .long("minimum-availability")
+                         .takes_value(true)
+                         .possible_values(radarr::MinimumAvailability::SELECTABLE)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("missing")
+                    .about("List the films in each collection that aren't in the library, and optionally add them")
+                    .arg(Arg::with_name("collection_id")
+                         .help("The IDs of the collections to check (default: all of them)")
+                         .multiple(true)
+                         )
+                    .arg(Arg::with_name("include-excluded")
+                         .help("Include films that are on the import exclusion list")
+                         .long("include-excluded")
+                         )
+                    .arg(Arg::with_name("add")
+                         .help("Add every missing film")
+                         .long("add")
+                         )
+                    .arg(Arg::with_name("quality-profile")
+                         .help("Name or ID of the quality profile to add films with (default: the collection's)")
+                         .long("quality-profile")
+                         .takes_value(true)
+                         .requires("add")
+                         )
+                    .arg(Arg::with_name("root-folder")
+                         .help("The root folder to add films to (default: the collection's)")
+                         .long("root-folder")
+                         .takes_value(true)
+                         .requires("add")
+                         )
+                    .arg(Arg::with_name("no-search")
+                         .help("Do not search for the films. Just add them.")
+                         .long("no-search")
+                         .requires("add")
+                         )
+                    .arg(Arg::with_name("yes")
+                         .help("Don't ask for confirmation before adding")
+                         .long("yes")
+                         .short("y")
+                         .requires("add")
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, collections_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if collections_matches.subcommand_matches("list").is_some() {
+        list(client, matches)?;
+    } else if let Some(show_matches) = collections_matches.subcommand_matches("show") {
+        let id = parse_id(show_matches.value_of("collection_id").unwrap(), "collection_id")?;
+        handle_resp(matches, client.get_collection(id)?)?;
+    } else if let Some(set_matches) = collections_matches.subcommand_matches("set") {
+        set(client, matches, set_matches)?;
+    } else if let Some(missing_matches) = collections_matches.subcommand_matches("missing") {
+        missing(client, matches, missing_matches)?;
+    } else {
+        return Err("Missing collections subcommand. See `radarr collections --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn list(client: &radarr::Client, matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let collections = client.collections()?;
+
+    if matches.is_present("json") {
+        print_data(matches, &collections.data);
+        return Ok(());
+    }
+
+    println!("{:>6}  {:9}  {:>7}  TITLE", "ID", "MONITORED", "MISSING");
+
+    for collection in collections.data.iter() {
+        let missing = format!("{}/{}", collection.missing_movies(false).len(), collection.movies.len());
+
+        println!("{:>6}  {:9}  {:>7}  {}", collection.id, collection.monitored, missing, collection.title);
+    }
+
+    Ok(())
+}
+
+fn set(client: &radarr::Client, matches: &ArgMatches, set_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let id = parse_id(set_matches.value_of("collection_id").unwrap(), "collection_id")?;
+    let mut collection = *client.get_collection(id)?.data;
+
+    if let Some(monitored) = set_matches.value_of("monitored") {
+        collection.monitored = monitored == "true";
+    }
+
+    if let Some(search_on_add) = set_matches.value_of("search-on-add") {
+        collection.search_on_add = search_on_add == "true";
+    }
+
+    if let Some(quality_profile) = set_matches.value_of("quality-profile") {
+        collection.quality_profile_id = quality_profile_id(client, quality_profile)?;
+    }
+
+    if let Some(root_folder) = set_matches.value_of("root-folder") {
+        collection.root_folder_path = Some(String::from(root_folder));
+    }
+
+    if let Some(minimum_availability) = set_matches.value_of("minimum-availability") {
+        collection.minimum_availability = Some(minimum_availability.parse()?);
+    }
+
+    handle_resp(matches, client.update_collection(&collection)?)
+}
+
+fn missing(client: &radarr::Client, matches: &ArgMatches, missing_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let mut collections = *client.collections()?.data;
+
+    if let Some(ids) = missing_matches.values_of("collection_id") {
+        let ids: Vec<u32> = ids.map(|id| parse_id(id, "collection_id")).collect::<Result<_, _>>()?;
+
+        for id in ids.iter() {
+            if !collections.iter().any(|collection| collection.id == *id) {
+                return Err(format!("No collection with ID {}", id).into());
+            }
+        }
+
+        collections.retain(|collection| ids.contains(&collection.id));
+    }
+
+    let include_excluded = missing_matches.is_present("include-excluded");
+    let report: Vec<(&radarr::Collection, Vec<&radarr::CollectionMovie>)> = collections.iter()
+        .map(|collection| (collection, collection.missing_movies(include_excluded)))
+        .filter(|(_, missing)| !missing.is_empty())
+        .collect();
+
+    if matches.is_present("json") {
+        let report: Vec<_> = report.iter()
+            .map(|(collection, missing)| json!({
+                "id": collection.id,
+                "title": collection.title,
+                "missing": missing,
+            }))
+            .collect();
+
+        print_data(matches, &report);
+    } else {
+        for (collection, missing) in report.iter() {
+            println!("{} ({}/{} missing)", collection.title, missing.len(), collection.movies.len());
+
+            for movie in missing.iter() {
+                let excluded = if movie.is_excluded { "  [excluded]" } else { "" };
+                println!("  {}  {} (tmdb {}){}", movie.year, movie.title, movie.tmdb_id, excluded);
+            }
+        }
+    }
+
+    if !missing_matches.is_present("add") || report.is_empty() {
+        return Ok(());
+    }
+
+    let count: usize = report.iter().map(|(_, missing)| missing.len()).sum();
+    if !missing_matches.is_present("yes") && !confirm(&format!("Add {} films?", count))? {
+        eprintln!("Aborted.");
+        return Ok(());
+    }
+
+    let quality_profile_id = match missing_matches.value_of("quality-profile") {
+        Some(quality_profile) => Some(quality_profile_id(client, quality_profile)?),
+        None => None,
+    };
+    let root_folder = missing_matches.value_of("root-folder");
+
+    let mut failed = 0;
+
+    for (collection, missing) in report.iter() {
+        let quality_profile_id = quality_profile_id
+            .or(Some(collection.quality_profile_id).filter(|id| *id != 0))
+            .ok_or_else(|| format!("{} has no quality profile set. Pass --quality-profile.", collection.title))?;
+        let root_folder = root_folder
+            .or(collection.root_folder_path.as_deref().filter(|path| !path.is_empty()))
+            .ok_or_else(|| format!("{} has no root folder set. Pass --root-folder.", collection.title))?;
+
+        for movie in missing.iter() {
+            let mut payload = radarr::AddMoviePayload::from_collection_movie(movie, quality_profile_id);
+            payload.set_root_folder_path(root_folder);
+            payload.set_search_for_movie(!missing_matches.is_present("no-search"));
+
+            match client.add_movie(&payload) {
+                Ok(_) => eprintln!("added {} ({})", movie.title, movie.year),
+                Err(err) => {
+                    eprintln!("failed to add {} ({}): {}", movie.title, movie.year, err);
+                    failed += 1;
+                },
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} films could not be added", failed, count).into());
+    }
+
+    Ok(())
+}
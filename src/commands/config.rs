@@ -0,0 +1,98 @@
+use std::error::Error;
+use std::fs;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::print_data;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("config")
+        .about("Export and apply a whole instance's configuration as a single document")
+        .subcommand(SubCommand::with_name("export")
+                    .about("Dump tags, root folders, custom formats, quality profiles, naming, media management, indexers, download clients and notifications")
+                    .arg(Arg::with_name("format")
+                         .help("The document format (default: yaml, or picked from the --output extension)")
+                         .long("format")
+                         .takes_value(true)
+                         .possible_values(&["yaml", "json"])
+                         )
+                    .arg(Arg::with_name("output")
+                         .help("Write to this file instead of stdout")
+                         .long("output")
+                         .short("o")
+                         .takes_value(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("apply")
+                    .about("Create or update whatever differs from the given document. Nothing is deleted.")
+                    .arg(Arg::with_name("file")
+                         .help("The yaml or json document to apply")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("dry-run")
+                         .help("Only show what would change")
+                         .long("dry-run")
+                         .short("n")
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, config_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if let Some(export_matches) = config_matches.subcommand_matches("export") {
+        let settings = radarr::InstanceSettings::export(client)?;
+        let output = export_matches.value_of("output");
+        let format = export_matches.value_of("format")
+            .unwrap_or_else(|| output.map(format_for_path).unwrap_or("yaml"));
+
+        let document = match format {
+            "json" => serde_json::to_string_pretty(&settings)? + "\n",
+            _ => serde_yaml::to_string(&settings)?,
+        };
+
+        match output {
+            Some(file_path) => fs::write(file_path, document)?,
+            None => print!("{}", document),
+        }
+    } else if let Some(apply_matches) = config_matches.subcommand_matches("apply") {
+        let file_path = apply_matches.value_of("file").unwrap();
+        let dry_run = apply_matches.is_present("dry-run");
+        let contents = fs::read_to_string(file_path)?;
+
+        let settings: radarr::InstanceSettings = match format_for_path(file_path) {
+            "json" => serde_json::from_str(&contents)?,
+            _ => serde_yaml::from_str(&contents)?,
+        };
+
+        let changes = settings.apply(client, dry_run)?;
+
+        if matches.is_present("json") {
+            print_data(matches, &changes);
+        } else {
+            print_changes(&changes, dry_run);
+        }
+    } else {
+        return Err("Missing config subcommand. See `radarr config --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn format_for_path(file_path: &str) -> &'static str {
+    if file_path.ends_with(".json") {
+        "json"
+    } else {
+        "yaml"
+    }
+}
+
+fn print_changes(changes: &[radarr::SettingsChange], dry_run: bool) {
+    let prefix = if dry_run { "would " } else { "" };
+
+    for change in changes {
+        match change.action {
+            radarr::ChangeAction::Create => println!("+ {}create {}/{}", prefix, change.section, change.name),
+            radarr::ChangeAction::Update => println!("~ {}update {}/{}", prefix, change.section, change.name),
+            radarr::ChangeAction::Unchanged => println!("  unchanged {}/{}", change.section, change.name),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{parse_id, print_data};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("credits")
+        .about("List the cast and crew of a movie, with their TMDb person IDs")
+        .arg(Arg::with_name("movie_id")
+             .help("The ID of the movie")
+             .required(true)
+             )
+        .arg(Arg::with_name("type")
+             .help("Only list cast or crew")
+             .long("type")
+             .takes_value(true)
+             .possible_values(&["cast", "crew"])
+             )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, credits_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let movie_id = parse_id(credits_matches.value_of("movie_id").unwrap(), "movie_id")?;
+    let mut credits = *client.credits(movie_id)?.data;
+
+    if let Some(credit_type) = credits_matches.value_of("type") {
+        credits.retain(|credit| credit.credit_type.eq_ignore_ascii_case(credit_type));
+    }
+
+    // cast in billing order, then crew
+    credits.sort_by_key(|credit| (!credit.is_cast(), credit.order));
+
+    if matches.is_present("json") {
+        print_data(matches, &credits);
+        return Ok(());
+    }
+
+    let width = credits.iter()
+        .map(|credit| credit.person_name.len())
+        .max()
+        .unwrap_or(0)
+        .max("NAME".len());
+
+    println!("{:4}  {:>9}  {:width$}  ROLE", "TYPE", "TMDB", "NAME", width = width);
+
+    for credit in credits.iter() {
+        println!("{:4}  {:>9}  {:width$}  {}",
+                 credit.credit_type,
+                 credit.person_tmdb_id,
+                 credit.person_name,
+                 credit.role(),
+                 width = width);
+    }
+
+    Ok(())
+}
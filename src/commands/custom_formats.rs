@@ -0,0 +1,125 @@
+use std::error::Error;
+use std::fs;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+use serde_json::Value;
+
+use crate::{handle_resp, parse_id};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("custom-formats")
+        .about("Manage custom formats, including import/export in the web UI's json format")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List all custom formats")
+                    )
+        .subcommand(SubCommand::with_name("show")
+                    .about("Show the custom format with the given ID")
+                    .arg(Arg::with_name("custom_format_id")
+                         .help("The ID of the custom format")
+                         .required(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("export")
+                    .about("Export custom formats as json. A single ID exports a single object, like the web UI.")
+                    .arg(Arg::with_name("custom_format_id")
+                         .help("The IDs of the custom formats to export (default: all of them)")
+                         .multiple(true)
+                         )
+                    .arg(Arg::with_name("output")
+                         .help("Write to this file instead of stdout")
+                         .long("output")
+                         .short("o")
+                         .takes_value(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("import")
+                    .about("Import custom formats from exported json, updating any with the same name")
+                    .arg(Arg::with_name("file")
+                         .help("The file to import, containing a single custom format or a list of them. Use `-` for stdin.")
+                         .required(true)
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, cf_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if cf_matches.subcommand_matches("list").is_some() {
+        handle_resp(matches, client.custom_formats()?)?;
+    } else if let Some(show_matches) = cf_matches.subcommand_matches("show") {
+        let id = parse_id(show_matches.value_of("custom_format_id").unwrap(), "custom_format_id")?;
+        handle_resp(matches, client.get_custom_format(id)?)?;
+    } else if let Some(export_matches) = cf_matches.subcommand_matches("export") {
+        export(client, export_matches)?;
+    } else if let Some(import_matches) = cf_matches.subcommand_matches("import") {
+        import(client, import_matches)?;
+    } else {
+        return Err("Missing custom-formats subcommand. See `radarr custom-formats --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn export(client: &radarr::Client, export_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let custom_formats = client.custom_formats()?;
+
+    let exported = match export_matches.values_of("custom_format_id") {
+        Some(ids) => {
+            let ids: Vec<u32> = ids.map(|id| parse_id(id, "custom_format_id")).collect::<Result<_, _>>()?;
+            let mut exported = vec![];
+
+            for id in ids.iter() {
+                let custom_format = custom_formats.data.iter()
+                    .find(|custom_format| custom_format.id == Some(*id))
+                    .ok_or_else(|| format!("No custom format with ID {}", id))?;
+
+                exported.push(custom_format.export());
+            }
+
+            if exported.len() == 1 {
+                exported.remove(0)
+            } else {
+                Value::Array(exported)
+            }
+        },
+        None => Value::Array(custom_formats.data.iter().map(radarr::CustomFormat::export).collect()),
+    };
+
+    let json = serde_json::to_string_pretty(&exported)?;
+
+    match export_matches.value_of("output") {
+        Some(file_path) => fs::write(file_path, json + "\n")?,
+        None => println!("{}", json),
+    }
+
+    Ok(())
+}
+
+fn import(client: &radarr::Client, import_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let data_source = radarr::DataSource::from(import_matches.value_of("file"), None).unwrap();
+    let data = data_source.read()?;
+
+    let imported: Vec<radarr::CustomFormat> = match serde_json::from_str(&data)? {
+        Value::Array(values) => values.into_iter().map(serde_json::from_value).collect::<Result<_, _>>()?,
+        value => vec![serde_json::from_value(value)?],
+    };
+
+    let existing = client.custom_formats()?;
+
+    for mut custom_format in imported {
+        let current = existing.data.iter().find(|current| current.name == custom_format.name);
+
+        match current {
+            Some(current) => {
+                custom_format.id = current.id;
+                client.update_custom_format(&custom_format)?;
+                println!("updated {}", custom_format.name);
+            },
+            None => {
+                custom_format.id = None;
+                client.create_custom_format(&custom_format)?;
+                println!("created {}", custom_format.name);
+            },
+        }
+    }
+
+    Ok(())
+}
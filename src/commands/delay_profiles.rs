@@ -0,0 +1,190 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id};
+use super::{confirm, tag_ids};
+
+// the flags shared by `add` and `set`. For `set` anything left out keeps its current value.
+fn profile_args<'a, 'b>(command: App<'a, 'b>) -> App<'a, 'b> {
+    command
+        .arg(Arg::with_name("usenet_delay")
+             .help("Minutes to wait before grabbing a usenet release")
+             .long("usenet-delay")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("torrent_delay")
+             .help("Minutes to wait before grabbing a torrent release")
+             .long("torrent-delay")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("preferred_protocol")
+             .help("The protocol to prefer when both are enabled")
+             .long("preferred-protocol")
+             .takes_value(true)
+             .possible_values(&["usenet", "torrent"])
+             )
+        .arg(Arg::with_name("protocols")
+             .help("Which protocols are enabled")
+             .long("protocols")
+             .takes_value(true)
+             .possible_values(&["both", "usenet", "torrent"])
+             )
+        .arg(Arg::with_name("bypass_if_highest_quality")
+             .help("Skip the delay when the release is the profile's highest quality")
+             .long("bypass-if-highest-quality")
+             .takes_value(true)
+             .possible_values(&["true", "false"])
+             )
+        .arg(Arg::with_name("tag")
+             .help("Only apply the profile to movies with this tag. Can be given more than once, and tags that don't exist are created.")
+             .long("tag")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+}
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("delay-profiles")
+        .about("Manage delay profiles")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List all delay profiles in the order they're matched")
+                    )
+        .subcommand(profile_args(SubCommand::with_name("add")
+                    .about("Add a delay profile. It's matched before the default profile.")
+                    ))
+        .subcommand(profile_args(SubCommand::with_name("set")
+                    .about("Change a delay profile")
+                    .arg(Arg::with_name("delay_profile_id")
+                         .help("The ID of the delay profile")
+                         .required(true)
+                         )
+                    ))
+        .subcommand(SubCommand::with_name("move")
+                    .about("Change the order delay profiles are matched in")
+                    .arg(Arg::with_name("delay_profile_id")
+                         .help("The ID of the delay profile to move")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("after")
+                         .help("Put it right after the delay profile with this ID (default: move it to the top)")
+                         .long("after")
+                         .takes_value(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("delete")
+                    .about("Delete a delay profile")
+                    .arg(Arg::with_name("delay_profile_id")
+                         .help("The ID of the delay profile")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("yes")
+                         .help("Don't ask for confirmation")
+                         .long("yes")
+                         .short("y")
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, dp_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if dp_matches.subcommand_matches("list").is_some() {
+        let mut profiles = client.delay_profiles()?;
+        profiles.data.sort_by_key(|profile| profile.order);
+
+        handle_resp(matches, profiles)?;
+    } else if let Some(add_matches) = dp_matches.subcommand_matches("add") {
+        let mut profile = radarr::DelayProfile::default();
+        update_from_matches(client, &mut profile, add_matches)?;
+
+        if profile.tags.is_empty() {
+            return Err("A delay profile needs at least one --tag. Use `set` to change the default profile.".into());
+        }
+
+        handle_resp(matches, client.create_delay_profile(&profile)?)?;
+    } else if let Some(set_matches) = dp_matches.subcommand_matches("set") {
+        let mut profile = find(client, set_matches)?;
+        update_from_matches(client, &mut profile, set_matches)?;
+
+        handle_resp(matches, client.update_delay_profile(&profile)?)?;
+    } else if let Some(move_matches) = dp_matches.subcommand_matches("move") {
+        let id = parse_id(move_matches.value_of("delay_profile_id").unwrap(), "delay_profile_id")?;
+        let after_id = match move_matches.value_of("after") {
+            Some(after) => Some(parse_id(after, "after")?),
+            None => None,
+        };
+
+        handle_resp(matches, client.reorder_delay_profile(id, after_id)?)?;
+    } else if let Some(del_matches) = dp_matches.subcommand_matches("delete") {
+        let profile = find(client, del_matches)?;
+        let id = profile.id.unwrap_or_default();
+
+        if profile.is_default() {
+            return Err("The default delay profile (ID 1) can't be deleted.".into());
+        }
+
+        if !del_matches.is_present("yes") && !confirm(&format!("Delete delay profile {}?", id))? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+
+        handle_resp(matches, client.delete_delay_profile(id)?)?;
+    } else {
+        return Err("Missing delay-profiles subcommand. See `radarr delay-profiles --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn find(client: &radarr::Client, matches: &ArgMatches) -> Result<radarr::DelayProfile, Box<dyn Error>> {
+    let id = parse_id(matches.value_of("delay_profile_id").unwrap(), "delay_profile_id")?;
+
+    client.delay_profiles()?.data.into_iter()
+        .find(|profile| profile.id == Some(id))
+        .ok_or_else(|| format!("No delay profile with ID {}", id).into())
+}
+
+fn update_from_matches(client: &radarr::Client, profile: &mut radarr::DelayProfile, matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if let Some(delay) = matches.value_of("usenet_delay") {
+        profile.usenet_delay = parse_minutes(delay, "usenet-delay")?;
+    }
+
+    if let Some(delay) = matches.value_of("torrent_delay") {
+        profile.torrent_delay = parse_minutes(delay, "torrent-delay")?;
+    }
+
+    if let Some(protocol) = matches.value_of("preferred_protocol") {
+        profile.preferred_protocol = String::from(protocol);
+    }
+
+    if let Some(protocols) = matches.value_of("protocols") {
+        profile.enable_usenet = protocols != "torrent";
+        profile.enable_torrent = protocols != "usenet";
+
+        // a disabled protocol can't be the preferred one
+        if !profile.enable_usenet {
+            profile.preferred_protocol = String::from("torrent");
+        } else if !profile.enable_torrent {
+            profile.preferred_protocol = String::from("usenet");
+        }
+    }
+
+    if let Some(bypass) = matches.value_of("bypass_if_highest_quality") {
+        profile.bypass_if_highest_quality = bypass == "true";
+    }
+
+    if let Some(labels) = matches.values_of("tag") {
+        if profile.is_default() {
+            return Err("The default delay profile (ID 1) applies to every movie and can't have tags.".into());
+        }
+
+        profile.tags = tag_ids(client, labels)?;
+    }
+
+    Ok(())
+}
+
+fn parse_minutes(value: &str, name: &str) -> Result<u32, Box<dyn Error>> {
+    value.parse::<u32>()
+        .map_err(|_| format!("Failed to parse {} as minutes: `{}`", name, value).into())
+}
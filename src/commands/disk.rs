@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use clap::{App, SubCommand, ArgMatches};
+
+use crate::print_data;
+use super::format_bytes;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("disk")
+        .about("Show used, free and total space for each disk Radarr can see")
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, _disk_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let disks = client.disk_space()?;
+
+    if matches.is_present("json") {
+        print_data(matches, &disks.data);
+        return Ok(());
+    }
+
+    let width = disks.data.iter()
+        .map(|disk| disk.path.len())
+        .max()
+        .unwrap_or(0)
+        .max("PATH".len());
+
+    println!("{:width$}  {:>10}  {:>10}  {:>10}  {:>6}", "PATH", "USED", "FREE", "TOTAL", "USED%", width = width);
+
+    for disk in disks.data.iter() {
+        println!("{:width$}  {:>10}  {:>10}  {:>10}  {:>5.1}%",
+                 disk.path,
+                 format_bytes(disk.used_space()),
+                 format_bytes(disk.free_space),
+                 format_bytes(disk.total_space),
+                 disk.used_percent(),
+                 width = width);
+    }
+
+    Ok(())
+}
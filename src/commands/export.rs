@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::fs;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use super::list::{added_args, added_filter};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    added_args(SubCommand::with_name("export")
+        .about("Export the tracked movies as csv that Letterboxd or IMDb can import")
+        .arg(Arg::with_name("format")
+             .help("The csv layout to write")
+             .long("format")
+             .takes_value(true)
+             .possible_values(&["letterboxd", "imdb", "csv"])
+             .default_value("csv")
+             )
+        .arg(Arg::with_name("has-file")
+             .help("Only export movies that have been downloaded")
+             .long("has-file")
+             .conflicts_with("missing-file")
+             )
+        .arg(Arg::with_name("missing-file")
+             .help("Only export movies that haven't been downloaded")
+             .long("missing-file")
+             )
+        .arg(Arg::with_name("monitored")
+             .help("Only export monitored movies")
+             .long("monitored")
+             .conflicts_with("unmonitored")
+             )
+        .arg(Arg::with_name("unmonitored")
+             .help("Only export unmonitored movies")
+             .long("unmonitored")
+             )
+        .arg(Arg::with_name("tag")
+             .help("Only export movies with this tag. Can be given more than once to require several tags.")
+             .long("tag")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+        .arg(Arg::with_name("output")
+             .help("Write to this file instead of stdout")
+             .long("output")
+             .short("o")
+             .takes_value(true)
+             ))
+}
+
+pub fn run(client: &radarr::Client, _matches: &ArgMatches, export_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let format: radarr::ExportFormat = export_matches.value_of("format").unwrap().parse()?;
+
+    let filter = radarr::MovieFilter {
+        has_file: flag_filter(export_matches, "has-file", "missing-file"),
+        monitored: flag_filter(export_matches, "monitored", "unmonitored"),
+        tags: existing_tag_ids(client, export_matches)?,
+        ..added_filter(export_matches)?
+    };
+
+    let mut movies = *client.list_movies()?.data;
+    movies.retain(|movie| filter.matches(movie));
+    movies.sort_by(|a, b| a.sort_title.cmp(&b.sort_title));
+
+    let csv = radarr::export_movies(format, &movies);
+
+    match export_matches.value_of("output") {
+        Some(file_path) => fs::write(file_path, csv)?,
+        None => print!("{}", csv),
+    }
+
+    Ok(())
+}
+
+fn flag_filter(matches: &ArgMatches, yes: &str, no: &str) -> Option<bool> {
+    if matches.is_present(yes) {
+        Some(true)
+    } else if matches.is_present(no) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+// unlike when adding, filtering on a tag that doesn't exist is a mistake rather than a new tag
+fn existing_tag_ids(client: &radarr::Client, matches: &ArgMatches) -> Result<Vec<u32>, Box<dyn Error>> {
+    let labels = match matches.values_of("tag") {
+        Some(labels) => labels,
+        None => return Ok(vec![]),
+    };
+
+    let tags = client.tags()?;
+
+    labels
+        .map(|label| {
+            tags.data.iter()
+                .find(|tag| tag.label.eq_ignore_ascii_case(label))
+                .map(|tag| tag.id)
+                .ok_or_else(|| format!("No tag named `{}`", label).into())
+        })
+        .collect()
+}
@@ -0,0 +1,120 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("files")
+        .about("Manage the files attached to movies")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List the files for the movie with the given ID")
+                    .arg(Arg::with_name("movie_id")
+                         .help("The ID of the movie")
+                         .required(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("show")
+                    .about("Show the movie file with the given ID, including its media info")
+                    .arg(Arg::with_name("file_id")
+                         .help("The ID of the movie file")
+                         .required(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("delete")
+                    .about("Delete the given movie files from disk")
+                    .arg(Arg::with_name("file_id")
+                         .help("The IDs of the movie files to delete")
+                         .required(true)
+                         .multiple(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("set-quality")
+                    .about("Reassign the quality, and optionally the edition and languages, of the given movie files")
+                    .arg(Arg::with_name("file_id")
+                         .help("The IDs of the movie files to update")
+                         .required(true)
+                         .multiple(true)
+                         )
+                    .arg(Arg::with_name("quality")
+                         .help("The quality to assign, by name (eg: `Bluray-1080p`) or ID")
+                         .long("quality")
+                         .short("q")
+                         .takes_value(true)
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("proper")
+                         .help("Mark the files as a proper/repack (revision 2)")
+                         .long("proper")
+                         )
+                    .arg(Arg::with_name("edition")
+                         .help("Also set the edition (eg: `Director's Cut`)")
+                         .long("edition")
+                         .takes_value(true)
+                         )
+                    .arg(Arg::with_name("language")
+                         .help("Also set the languages, by name (eg: `English`) or ID. Can be given more than once.")
+                         .long("language")
+                         .takes_value(true)
+                         .multiple(true)
+                         .number_of_values(1)
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, files_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if let Some(list_matches) = files_matches.subcommand_matches("list") {
+        let movie_id = parse_id(list_matches.value_of("movie_id").unwrap(), "movie_id")?;
+        handle_resp(matches, client.movie_files(movie_id)?)?;
+    } else if let Some(show_matches) = files_matches.subcommand_matches("show") {
+        let file_id = parse_id(show_matches.value_of("file_id").unwrap(), "file_id")?;
+        handle_resp(matches, client.get_movie_file(file_id)?)?;
+    } else if let Some(delete_matches) = files_matches.subcommand_matches("delete") {
+        let file_ids = file_ids_from_matches(delete_matches)?;
+
+        if file_ids.len() == 1 {
+            handle_resp(matches, client.delete_movie_file(file_ids[0])?)?;
+        } else {
+            handle_resp(matches, client.delete_movie_files(&file_ids)?)?;
+        }
+    } else if let Some(quality_matches) = files_matches.subcommand_matches("set-quality") {
+        let file_ids = file_ids_from_matches(quality_matches)?;
+        let definitions = client.quality_definitions()?;
+        let value = quality_matches.value_of("quality").unwrap();
+        let definition = radarr::QualityDefinition::find(&definitions.data, value)
+            .ok_or_else(|| format!("Unknown quality: {}", value))?;
+
+        let mut quality = radarr::Quality::new(definition.quality.clone());
+        if quality_matches.is_present("proper") {
+            quality.revision.version = 2;
+        }
+
+        let mut payload = radarr::MovieFileEditorPayload::new(file_ids);
+        payload.set_quality(quality);
+
+        if let Some(edition) = quality_matches.value_of("edition") {
+            payload.set_edition(edition);
+        }
+
+        if let Some(values) = quality_matches.values_of("language") {
+            let languages = client.languages()?;
+            let languages = values
+                .map(|value| radarr::Language::find(&languages.data, value).cloned().ok_or_else(|| format!("Unknown language: {}", value)))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            payload.set_languages(languages);
+        }
+
+        handle_resp(matches, client.edit_movie_files(&payload)?)?;
+    } else {
+        return Err("Missing files subcommand. See `radarr files --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn file_ids_from_matches(matches: &ArgMatches) -> Result<Vec<u32>, Box<dyn Error>> {
+    matches.values_of("file_id").unwrap()
+        .map(|id| parse_id(id, "file_id"))
+        .collect()
+}
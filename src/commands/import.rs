@@ -0,0 +1,113 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+use serde_json::{json, Value};
+
+use crate::{handle_resp, parse_id, print_data};
+use super::confirm;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("import")
+        .about("Manually import movie files from a folder (eg: a downloads folder)")
+        .arg(Arg::with_name("folder")
+             .help("The folder, as seen by the Radarr server, to scan for movie files")
+             .required(true)
+             )
+        .arg(Arg::with_name("movie")
+             .help("Import everything in the folder as the movie with this ID")
+             .long("movie")
+             .short("m")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("mode")
+             .help("Whether to move or copy the files into the library")
+             .long("mode")
+             .takes_value(true)
+             .possible_values(&["move", "copy"])
+             .default_value("move")
+             )
+        .arg(Arg::with_name("yes")
+             .help("Don't ask for confirmation before importing")
+             .long("yes")
+             .short("y")
+             )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, import_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let folder = import_matches.value_of("folder").unwrap();
+    let movie_id = match import_matches.value_of("movie") {
+        Some(movie_id) => Some(parse_id(movie_id, "movie")?),
+        None => None,
+    };
+    let import_mode = match import_matches.value_of("mode") {
+        Some("copy") => radarr::ImportMode::Copy,
+        _ => radarr::ImportMode::Move,
+    };
+
+    let candidates = client.manual_import_candidates(folder, movie_id)?;
+    let candidates = &candidates.data;
+
+    // with `--json` the candidates are printed once, together with the import command (if any)
+    let json = matches.is_present("json");
+
+    if !json {
+        for candidate in candidates.iter() {
+            print_candidate(candidate);
+        }
+    }
+
+    let files: Vec<radarr::ManualImportFile> = candidates.iter()
+        .filter(|candidate| candidate.is_importable())
+        .filter_map(radarr::ManualImportFile::from_item)
+        .collect();
+
+    if files.is_empty() {
+        if json {
+            print_data(matches, &output(candidates, Value::Null));
+        }
+
+        return Err(format!("No importable files found in {}", folder).into());
+    }
+
+    let prompt = format!("Import {} of {} files ({:?})?", files.len(), candidates.len(), import_mode);
+    if !import_matches.is_present("yes") && !confirm(&prompt)? {
+        if json {
+            print_data(matches, &output(candidates, Value::Null));
+        }
+
+        eprintln!("Aborted.");
+        return Ok(());
+    }
+
+    let payload = radarr::ManualImportPayload::new(files, import_mode);
+    let resp = client.manual_import(&payload)?;
+
+    if json {
+        let command = json!(resp.data);
+        return handle_resp(matches, radarr::Response::new(resp.api_response, output(candidates, command)));
+    }
+
+    handle_resp(matches, resp)
+}
+
+// the single document `--json` prints
+fn output(candidates: &[radarr::ManualImportItem], command: Value) -> Value {
+    json!({ "candidates": candidates, "command": command })
+}
+
+fn print_candidate(candidate: &radarr::ManualImportItem) {
+    let movie = candidate.movie.as_ref()
+        .map(|movie| format!("{} ({}) [{}]", movie.title, movie.year, movie.id))
+        .unwrap_or_else(|| String::from("unknown movie"));
+    let quality = candidate.quality.as_ref()
+        .map(|quality| quality.quality.name.to_owned())
+        .unwrap_or_else(|| String::from("unknown quality"));
+    let marker = if candidate.is_importable() { "+" } else { "-" };
+
+    println!("{} {}", marker, candidate.path);
+    println!("    {} / {}", movie, quality);
+
+    for rejection in candidate.rejections.iter() {
+        println!("    rejected ({}): {}", rejection.rejection_type, rejection.reason);
+    }
+}
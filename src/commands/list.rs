@@ -0,0 +1,66 @@
+use std::error::Error;
+
+use chrono::Utc;
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::print_data;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    added_args(SubCommand::with_name("list")
+        .about("List all movies that are currently tracked"))
+}
+
+// `--added-after` and `--added-before`, for commands that filter the library by when movies were added
+pub fn added_args<'a, 'b>(command: App<'a, 'b>) -> App<'a, 'b> {
+    command
+        .arg(Arg::with_name("added-after")
+             .help("Only movies added on or after this date (eg: 2021-06-01 or 2021-06-01T12:00:00Z)")
+             .long("added-after")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("added-before")
+             .help("Only movies added before this date (eg: 2021-06-01 or 2021-06-01T12:00:00Z)")
+             .long("added-before")
+             .takes_value(true)
+             )
+}
+
+// a filter on only what `added_args` asks for
+pub fn added_filter(matches: &ArgMatches) -> Result<radarr::MovieFilter, Box<dyn Error>> {
+    let parse = |name| matches.value_of(name).map(radarr::parse_datetime).transpose();
+
+    Ok(radarr::MovieFilter {
+        added_after: parse("added-after")?,
+        added_before: parse("added-before")?,
+        ..Default::default()
+    })
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, list_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let filter = added_filter(list_matches)?;
+
+    let mut movies = *client.list_movies()?.data;
+    movies.retain(|movie| filter.matches(movie));
+
+    if matches.is_present("json") {
+        print_data(matches, &movies);
+        return Ok(());
+    }
+
+    movies.sort_by(|a, b| a.sort_title.cmp(&b.sort_title));
+
+    let now = Utc::now();
+
+    println!("{:>6}  {:9}  {:14}  TITLE", "ID", "STATUS", "ADDED");
+
+    for movie in movies.iter() {
+        println!("{:>6}  {:9}  {:14}  {} ({})",
+                 movie.id,
+                 movie.status,
+                 radarr::relative_time(movie.added, now),
+                 movie.title,
+                 movie.year);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id, print_data};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("logs")
+        .about("Show Radarr's log entries")
+        .arg(Arg::with_name("level")
+             .help("Only show entries at this level or above")
+             .long("level")
+             .short("l")
+             .takes_value(true)
+             .possible_values(&["trace", "debug", "info", "warn", "error", "fatal"])
+             )
+        .arg(Arg::with_name("limit")
+             .help("How many of the most recent entries to show (default: 50)")
+             .long("limit")
+             .short("n")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("page")
+             .help("Which page of --limit entries to show, counting back from the most recent (default: 1)")
+             .long("page")
+             .takes_value(true)
+             .conflicts_with("follow")
+             )
+        .arg(Arg::with_name("follow")
+             .help("Keep polling for new entries and print them as they arrive")
+             .long("follow")
+             .short("f")
+             )
+        .arg(Arg::with_name("interval")
+             .help("Seconds between polls when following (default: 5)")
+             .long("interval")
+             .takes_value(true)
+             .requires("follow")
+             )
+        .subcommand(SubCommand::with_name("files")
+                    .about("List the log files on the server")
+                    .arg(Arg::with_name("update")
+                         .help("List the updater's log files instead")
+                         .long("update")
+                         )
+                    )
+        .subcommand(SubCommand::with_name("download")
+                    .about("Download a log file")
+                    .arg(Arg::with_name("filename")
+                         .help("The name of the log file (eg: `radarr.txt`)")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("update")
+                         .help("Download one of the updater's log files instead")
+                         .long("update")
+                         )
+                    .arg(Arg::with_name("output")
+                         .help("Write the file into this directory instead of stdout")
+                         .long("output")
+                         .short("o")
+                         .takes_value(true)
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, logs_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if let Some(files_matches) = logs_matches.subcommand_matches("files") {
+        if files_matches.is_present("update") {
+            handle_resp(matches, client.update_log_files()?)?;
+        } else {
+            handle_resp(matches, client.log_files()?)?;
+        }
+    } else if let Some(download_matches) = logs_matches.subcommand_matches("download") {
+        download(client, download_matches)?;
+    } else {
+        tail(client, matches, logs_matches)?;
+    }
+
+    Ok(())
+}
+
+fn tail(client: &radarr::Client, matches: &ArgMatches, logs_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let level = logs_matches.value_of("level");
+    let limit = parse_id(logs_matches.value_of("limit").unwrap_or("50"), "limit")?;
+    let page = parse_id(logs_matches.value_of("page").unwrap_or("1"), "page")?;
+    let interval = parse_id(logs_matches.value_of("interval").unwrap_or("5"), "interval")?;
+
+    if page == 0 {
+        return Err("Pages start at 1".into());
+    }
+
+    if limit == 0 {
+        return Err("The limit must be at least 1".into());
+    }
+
+    let page = client.logs(page, limit, level)?;
+    let mut last_id = print_new_entries(matches, &page.data.records, 0);
+
+    if !logs_matches.is_present("follow") {
+        return Ok(());
+    }
+
+    loop {
+        thread::sleep(Duration::from_secs(u64::from(interval)));
+
+        let entries = entries_since(client, limit, level, last_id)?;
+        last_id = print_new_entries(matches, &entries, last_id);
+    }
+}
+
+// every entry newer than `last_id`, newest first like Radarr sends them. More than a page can
+// arrive between polls, so this pages back until it reaches `last_id` or runs out of entries.
+fn entries_since(client: &radarr::Client, limit: u32, level: Option<&str>, last_id: u32) -> Result<Vec<radarr::LogEntry>, Box<dyn Error>> {
+    let mut entries = vec![];
+    let mut page = 1;
+
+    loop {
+        let records = client.logs(page, limit, level)?.data.records;
+        let done = records.len() < limit as usize || records.iter().any(|entry| entry.id <= last_id);
+
+        entries.extend(records.into_iter().filter(|entry| entry.id > last_id));
+
+        if done {
+            return Ok(entries);
+        }
+
+        page += 1;
+    }
+}
+
+// print entries newer than `last_id` oldest first, returning the newest id seen. Radarr hands them
+// back newest first.
+fn print_new_entries(matches: &ArgMatches, entries: &[radarr::LogEntry], last_id: u32) -> u32 {
+    let mut newest = last_id;
+
+    for entry in entries.iter().rev().filter(|entry| entry.id > last_id) {
+        if matches.is_present("json") {
+            print_data(matches, entry);
+        } else {
+            println!("{} [{}] {}: {}",
+                     entry.time.format("%Y-%m-%d %H:%M:%S"),
+                     entry.level,
+                     entry.logger.as_deref().unwrap_or("-"),
+                     entry.message.as_deref().unwrap_or(""));
+
+            if let Some(exception) = &entry.exception {
+                println!("{}", exception);
+            }
+        }
+
+        newest = newest.max(entry.id);
+    }
+
+    newest
+}
+
+fn download(client: &radarr::Client, download_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let filename = download_matches.value_of("filename").unwrap();
+    let log_files = if download_matches.is_present("update") {
+        client.update_log_files()?
+    } else {
+        client.log_files()?
+    };
+
+    let log_file = log_files.data.iter()
+        .find(|log_file| log_file.filename == filename)
+        .ok_or_else(|| format!("No log file named {}", filename))?;
+
+    match download_matches.value_of("output") {
+        Some(dir) => {
+            let file_path = Path::new(dir).join(&log_file.filename);
+            let mut file = File::create(&file_path)?;
+            let resp = client.download_log_file(log_file, &mut file)?;
+
+            eprintln!("Downloaded {} ({} bytes)", file_path.display(), resp.data);
+        },
+        None => {
+            client.download_log_file(log_file, &mut io::stdout())?;
+        },
+    }
+
+    Ok(())
+}
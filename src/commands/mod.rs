@@ -0,0 +1,118 @@
+// Subcommands that have grown beyond a couple of lines live in their own module here. Each one
+// exposes a `subcommand()` returning its clap definition and a `run()` that handles its matches.
+
+use std::error::Error;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+pub mod add;
+pub mod add_many;
+pub mod backup;
+pub mod cinema_sync;
+pub mod collections;
+pub mod config;
+pub mod credits;
+pub mod custom_formats;
+pub mod delay_profiles;
+pub mod disk;
+pub mod export;
+pub mod files;
+pub mod import;
+pub mod list;
+pub mod logs;
+pub mod person;
+pub mod quality_definitions;
+pub mod release_profiles;
+pub mod remote_path_mappings;
+pub mod rename;
+pub mod settings;
+pub mod system;
+
+// ask a yes/no question on stderr and read the answer from stdin. Anything but `y` or `yes` is a no.
+pub fn confirm(prompt: &str) -> Result<bool, Box<dyn Error>> {
+    eprint!("{} [y/N] ", prompt);
+    io::stderr().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+
+    let answer = answer.trim().to_lowercase();
+    Ok(answer == "y" || answer == "yes")
+}
+
+// poll a queued command until Radarr reports it finished
+pub fn wait_for_command(client: &radarr::Client, id: u32) -> Result<radarr::CommandResponse, Box<dyn Error>> {
+    loop {
+        let command = client.get_command(id)?;
+
+        if command.data.is_finished() {
+            return Ok(*command.data);
+        }
+
+        thread::sleep(Duration::from_secs(2));
+    }
+}
+
+// turn tag labels into ids, creating any tags that don't exist yet like the web UI does
+pub fn tag_ids<'a, I: IntoIterator<Item = &'a str>>(client: &radarr::Client, labels: I) -> Result<Vec<u32>, Box<dyn Error>> {
+    let mut tags = *client.tags()?.data;
+    let mut ids = vec![];
+
+    for label in labels {
+        let id = match tags.iter().find(|tag| tag.label.eq_ignore_ascii_case(label)) {
+            Some(tag) => tag.id,
+            None => {
+                let tag = *client.create_tag(label)?.data;
+                let id = tag.id;
+                tags.push(tag);
+                id
+            },
+        };
+
+        ids.push(id);
+    }
+
+    Ok(ids)
+}
+
+// accept a quality profile by ID or by name
+pub fn quality_profile_id(client: &radarr::Client, value: &str) -> Result<u32, Box<dyn Error>> {
+    let profiles = client.quality_profiles()?;
+
+    profiles.data.iter()
+        .find(|profile| profile.id.map(|id| id.to_string()).as_deref() == Some(value) || profile.name.eq_ignore_ascii_case(value))
+        .and_then(|profile| profile.id)
+        .ok_or_else(|| format!("No quality profile named or with ID `{}`", value).into())
+}
+
+// render a byte count using binary units, eg: `1.5 GiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes_picks_largest_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(46_891_755_717_632), "42.6 TiB");
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+use serde::{Serialize, Deserialize};
+
+use crate::{parse_id, print_data};
+use super::{confirm, quality_profile_id};
+
+// Radarr only knows the credits of movies in the library, so by default the films are found by
+// scanning those. Radarr can't list a person's whole filmography though, so finding (and adding)
+// the missing films needs `--tmdb`, which asks TMDb directly with the user's own api key. Whether
+// a film is tracked and adding it still go through Radarr.
+const TMDB_API_URL: &str = "https://api.themoviedb.org/3";
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("person")
+        .about("Show the tracked films by a person, and with --tmdb the missing ones too, optionally adding those")
+        .after_help("Without --tmdb only tracked films are listed: Radarr only has credits for movies in the \
+                     library, so they are fetched movie by movie, which takes a while for big libraries. \
+                     With --tmdb the person's full filmography is fetched from api.themoviedb.org with your own \
+                     TMDb api key, so films that aren't tracked yet can be listed and added.")
+        .arg(Arg::with_name("tmdb_person_id")
+             .help("The TMDb ID of the person. See `radarr credits`.")
+             .required(true)
+             )
+        .arg(Arg::with_name("job")
+             .help("Only include films where the person had this crew job")
+             .long("job")
+             .takes_value(true)
+             .default_value("Director")
+             )
+        .arg(Arg::with_name("cast")
+             .help("Include films the person acted in instead of crew jobs")
+             .long("cast")
+             .conflicts_with("job")
+             )
+        .arg(Arg::with_name("tmdb")
+             .help("Fetch the person's full filmography from TMDb to find missing films. Needs a TMDb api key.")
+             .long("tmdb")
+             .requires("tmdb-api-key")
+             )
+        .arg(Arg::with_name("tmdb-api-key")
+             .help("The TMDb api key used with --tmdb")
+             .long("tmdb-api-key")
+             .takes_value(true)
+             .env("TMDB_API_KEY")
+             .hide_env_values(true)
+             )
+        .arg(Arg::with_name("add")
+             .help("Add every missing film")
+             .long("add")
+             .requires_all(&["tmdb", "quality-profile", "root-folder"])
+             )
+        .arg(Arg::with_name("quality-profile")
+             .help("Name or ID of the quality profile to add films with")
+             .long("quality-profile")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("root-folder")
+             .help("The root folder to add films to")
+             .long("root-folder")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("no-search")
+             .help("Do not search for the films. Just add them.")
+             .long("no-search")
+             .requires("add")
+             )
+        .arg(Arg::with_name("yes")
+             .help("Don't ask for confirmation before adding")
+             .long("yes")
+             .short("y")
+             .requires("add")
+             )
+}
+
+#[derive(Deserialize, Debug)]
+struct MovieCredits {
+    #[serde(default)]
+    cast: Vec<MovieCredit>,
+
+    #[serde(default)]
+    crew: Vec<MovieCredit>,
+}
+
+#[derive(Deserialize, Debug)]
+struct MovieCredit {
+    id: u32,
+    title: String,
+    release_date: Option<String>,
+    job: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+struct Film {
+    #[serde(rename = "tmdbId")]
+    tmdb_id: u32,
+
+    title: String,
+    year: Option<u32>,
+    tracked: bool,
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, person_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let person_id = parse_id(person_matches.value_of("tmdb_person_id").unwrap(), "tmdb_person_id")?;
+    let job = if person_matches.is_present("cast") { None } else { person_matches.value_of("job") };
+
+    let mut films = vec![];
+    let mut lookups = vec![];
+
+    if person_matches.is_present("tmdb") {
+        let api_key = person_matches.value_of("tmdb-api-key").unwrap();
+        let credits = movie_credits(api_key, person_id)?;
+
+        for mut film in filmography(&credits, job) {
+            match client.lookup_by_tmdb(film.tmdb_id) {
+                Ok(lookup) => {
+                    film.tracked = lookup.data.is_existing();
+                    films.push(film);
+                    lookups.push(*lookup.data);
+                },
+                Err(err) => eprintln!("{} (tmdb {}) couldn't be looked up, skipping: {}", film.title, film.tmdb_id, err),
+            }
+        }
+    } else {
+        eprintln!("Only tracked films are listed. Use --tmdb to find the missing ones.");
+        films = library_films(client, person_id, job)?;
+    }
+
+    if matches.is_present("json") {
+        print_data(matches, &films);
+    } else {
+        println!("{:7}  {:4}  {:>9}  TITLE", "STATUS", "YEAR", "TMDB");
+
+        for film in films.iter() {
+            let status = if film.tracked { "tracked" } else { "missing" };
+            let year = film.year.map(|year| year.to_string()).unwrap_or_else(|| String::from("----"));
+
+            println!("{:7}  {:4}  {:>9}  {}", status, year, film.tmdb_id, film.title);
+        }
+    }
+
+    let missing: Vec<&radarr::SearchResult> = lookups.iter().filter(|lookup| !lookup.is_existing()).collect();
+
+    if !person_matches.is_present("add") || missing.is_empty() {
+        return Ok(());
+    }
+
+    if !person_matches.is_present("yes") && !confirm(&format!("Add {} films?", missing.len()))? {
+        eprintln!("Aborted.");
+        return Ok(());
+    }
+
+    let quality_profile_id = quality_profile_id(client, person_matches.value_of("quality-profile").unwrap())?;
+    let root_folder = person_matches.value_of("root-folder").unwrap();
+    let mut failed = 0;
+
+    for lookup in missing.iter() {
+        let mut payload = match radarr::AddMoviePayload::from_movie_response(lookup) {
+            Ok(payload) => payload,
+            Err(err) => {
+                eprintln!("failed to add {} ({}): {}", lookup.title, lookup.year, err);
+                failed += 1;
+                continue;
+            },
+        };
+        payload.set_quality_profile_id(quality_profile_id);
+        payload.set_root_folder_path(root_folder);
+        payload.set_search_for_movie(!person_matches.is_present("no-search"));
+
+        match client.add_movie(&payload) {
+            Ok(_) => eprintln!("added {} ({})", lookup.title, lookup.year),
+            Err(err) => {
+                eprintln!("failed to add {} ({}): {}", lookup.title, lookup.year, err);
+                failed += 1;
+            },
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} films could not be added", failed, missing.len()).into());
+    }
+
+    Ok(())
+}
+
+// the tracked films the person is credited on, going through the credits Radarr has for every
+// movie in the library. Radarr has no credits for anything else, so these are never missing.
+fn library_films(client: &radarr::Client, person_id: u32, job: Option<&str>) -> Result<Vec<Film>, Box<dyn Error>> {
+    let mut movies = *client.list_movies()?.data;
+    movies.sort_by(|a, b| (a.year, &a.sort_title).cmp(&(b.year, &b.sort_title)));
+
+    let mut films = vec![];
+
+    for movie in movies.iter() {
+        let credits = match client.credits(movie.id) {
+            Ok(credits) => credits.data,
+            Err(err) => {
+                eprintln!("{} ({}) credits couldn't be fetched, skipping: {}", movie.title, movie.year, err);
+                continue;
+            },
+        };
+
+        if credits.iter().any(|credit| is_credited(credit, person_id, job)) {
+            films.push(Film {
+                tmdb_id: movie.tmdb_id.unwrap_or_default(),
+                title: movie.title.to_owned(),
+                year: Some(movie.year).filter(|year| *year > 0),
+                tracked: true,
+            });
+        }
+    }
+
+    Ok(films)
+}
+
+// whether a Radarr credit is the person in the crew job or (without one) the cast
+fn is_credited(credit: &radarr::Credit, person_id: u32, job: Option<&str>) -> bool {
+    if credit.person_tmdb_id != person_id {
+        return false;
+    }
+
+    match job {
+        Some(job) => !credit.is_cast() && credit.job.as_deref().is_some_and(|credit_job| credit_job.eq_ignore_ascii_case(job)),
+        None => credit.is_cast(),
+    }
+}
+
+fn movie_credits(api_key: &str, person_id: u32) -> Result<MovieCredits, Box<dyn Error>> {
+    if api_key.is_empty() {
+        return Err("The TMDb api key is empty".into());
+    }
+
+    // TMDb only takes the key in the query string and reqwest's errors start with the url, so the
+    // url is dropped (and the key masked, should it show up anyway) before an error gets to stderr
+    let hide_key = |err: reqwest::Error| -> Box<dyn Error> {
+        let message = err.to_string();
+        let message = err.url()
+            .and_then(|url| message.strip_prefix(&format!("{}: ", url)))
+            .unwrap_or(&message)
+            .replace(api_key, "<api key>");
+
+        format!("Couldn't fetch person {} from TMDb: {}", person_id, message).into()
+    };
+
+    let url = format!("{}/person/{}/movie_credits?api_key={}", TMDB_API_URL, person_id, api_key);
+    let mut resp = reqwest::get(&url).map_err(hide_key)?;
+
+    if !resp.status().is_success() {
+        return Err(format!("TMDb responded with {} for person {}", resp.status(), person_id).into());
+    }
+
+    Ok(serde_json::from_str(&resp.text().map_err(hide_key)?)?)
+}
+
+// the person's films, oldest first, for a crew job or (without one) the cast. A film shows up
+// once even if the person had several jobs on it.
+fn filmography(credits: &MovieCredits, job: Option<&str>) -> Vec<Film> {
+    let credits = match job {
+        Some(job) => credits.crew.iter()
+            .filter(|credit| credit.job.as_deref().is_some_and(|credit_job| credit_job.eq_ignore_ascii_case(job)))
+            .collect::<Vec<_>>(),
+        None => credits.cast.iter().collect(),
+    };
+
+    let mut seen = HashSet::new();
+    let mut films: Vec<(Option<&str>, Film)> = credits.into_iter()
+        .filter(|credit| seen.insert(credit.id))
+        .map(|credit| {
+            let release_date = credit.release_date.as_deref().filter(|date| !date.is_empty());
+            let year = release_date.and_then(|date| date.get(..4)).and_then(|year| year.parse().ok());
+
+            (release_date, Film { tmdb_id: credit.id, title: credit.title.to_owned(), year, tracked: false })
+        })
+        .collect();
+
+    // unreleased films without a date go last
+    films.sort_by(|(a, _), (b, _)| (a.is_none(), a).cmp(&(b.is_none(), b)));
+
+    films.into_iter().map(|(_, film)| film).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filmography_filters_by_job_and_sorts_by_release() {
+        let credits: MovieCredits = serde_json::from_value(serde_json::json!({
+            "cast": [
+                { "id": 1, "title": "Cameo", "release_date": "2005-01-01", "character": "Himself" },
+            ],
+            "crew": [
+                { "id": 604, "title": "The Matrix Reloaded", "release_date": "2003-05-15", "job": "Director" },
+                { "id": 603, "title": "The Matrix", "release_date": "1999-03-30", "job": "Director" },
+                { "id": 603, "title": "The Matrix", "release_date": "1999-03-30", "job": "Writer" },
+                { "id": 9, "title": "Untitled", "release_date": "", "job": "Director" },
+                { "id": 10, "title": "Bound", "release_date": "1996-09-13", "job": "Writer" },
+            ],
+        })).unwrap();
+
+        let films: Vec<(u32, Option<u32>)> = filmography(&credits, Some("director")).iter()
+            .map(|film| (film.tmdb_id, film.year))
+            .collect();
+        assert_eq!(films, vec![(603, Some(1999)), (604, Some(2003)), (9, None)]);
+
+        let films: Vec<u32> = filmography(&credits, None).iter().map(|film| film.tmdb_id).collect();
+        assert_eq!(films, vec![1]);
+    }
+
+    #[test]
+    fn library_credits_match_the_person_and_job() {
+        let credit = |person_id: u32, credit_type: &str, job: Option<&str>| -> radarr::Credit {
+            serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "personName": "Lana Wachowski",
+                "personTmdbId": person_id,
+                "job": job,
+                "type": credit_type,
+            })).unwrap()
+        };
+
+        assert!(is_credited(&credit(9339, "crew", Some("Director")), 9339, Some("director")));
+        assert!(!is_credited(&credit(9339, "crew", Some("Writer")), 9339, Some("director")));
+        assert!(!is_credited(&credit(9340, "crew", Some("Director")), 9339, Some("director")));
+        assert!(is_credited(&credit(9339, "cast", None), 9339, None));
+        assert!(!is_credited(&credit(9339, "crew", Some("Director")), 9339, None));
+    }
+}
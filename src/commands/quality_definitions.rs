@@ -0,0 +1,159 @@
+use std::error::Error;
+use std::fs;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, print_data};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("quality-definitions")
+        .about("Manage the size limits (MB per minute) of each quality")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List all quality definitions")
+                    )
+        .subcommand(SubCommand::with_name("set")
+                    .about("Change the size limits of a single quality")
+                    .arg(Arg::with_name("quality")
+                         .help("The quality, by name (eg: `Bluray-1080p`) or ID")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("min")
+                         .help("Minimum size")
+                         .long("min")
+                         .takes_value(true)
+                         )
+                    .arg(Arg::with_name("max")
+                         .help("Maximum size, or `unlimited`")
+                         .long("max")
+                         .takes_value(true)
+                         )
+                    .arg(Arg::with_name("preferred")
+                         .help("Preferred size, or `unlimited`")
+                         .long("preferred")
+                         .takes_value(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("export")
+                    .about("Export the size limits of every quality")
+                    .arg(Arg::with_name("output")
+                         .help("Write to this file instead of stdout. A .yml/.yaml extension writes yaml.")
+                         .long("output")
+                         .short("o")
+                         .takes_value(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("import")
+                    .about("Apply size limits from an exported json or yaml file")
+                    .arg(Arg::with_name("file")
+                         .help("The file to import")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("dry-run")
+                         .help("Only show what would change")
+                         .long("dry-run")
+                         .short("n")
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, qd_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if qd_matches.subcommand_matches("list").is_some() {
+        handle_resp(matches, client.quality_definitions()?)?;
+    } else if let Some(set_matches) = qd_matches.subcommand_matches("set") {
+        let definitions = client.quality_definitions()?;
+        let value = set_matches.value_of("quality").unwrap();
+        let mut definition = radarr::QualityDefinition::find(&definitions.data, value)
+            .ok_or_else(|| format!("Unknown quality: {}", value))?
+            .clone();
+
+        let mut limits = definition.limits();
+        if let Some(min) = set_matches.value_of("min") {
+            limits.min_size = parse_size(min, "min")?;
+        }
+        if let Some(max) = set_matches.value_of("max") {
+            limits.max_size = parse_size(max, "max")?;
+        }
+        if let Some(preferred) = set_matches.value_of("preferred") {
+            limits.preferred_size = parse_size(preferred, "preferred")?;
+        }
+
+        if !limits.is_valid() {
+            return Err(format!("Sizes for {} must be ordered min <= preferred <= max", limits.quality).into());
+        }
+
+        definition.set_limits(&limits);
+        handle_resp(matches, client.update_quality_definition(&definition)?)?;
+    } else if let Some(export_matches) = qd_matches.subcommand_matches("export") {
+        let definitions = client.quality_definitions()?;
+        let limits: Vec<radarr::QualitySizeLimits> = definitions.data.iter().map(radarr::QualityDefinition::limits).collect();
+
+        match export_matches.value_of("output") {
+            Some(file_path) if is_yaml(file_path) => fs::write(file_path, serde_yaml::to_string(&limits)?)?,
+            Some(file_path) => fs::write(file_path, serde_json::to_string_pretty(&limits)? + "\n")?,
+            None => println!("{}", serde_json::to_string_pretty(&limits)?),
+        }
+    } else if let Some(import_matches) = qd_matches.subcommand_matches("import") {
+        import(client, matches, import_matches)?;
+    } else {
+        return Err("Missing quality-definitions subcommand. See `radarr quality-definitions --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn import(client: &radarr::Client, matches: &ArgMatches, import_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let file_path = import_matches.value_of("file").unwrap();
+    let contents = fs::read_to_string(file_path)?;
+    let imported: Vec<radarr::QualitySizeLimits> = if is_yaml(file_path) {
+        serde_yaml::from_str(&contents)?
+    } else {
+        serde_json::from_str(&contents)?
+    };
+
+    let mut definitions = *client.quality_definitions()?.data;
+    let mut changed = vec![];
+
+    for limits in imported.iter() {
+        if !limits.is_valid() {
+            return Err(format!("Sizes for {} must be ordered min <= preferred <= max", limits.quality).into());
+        }
+
+        let definition = definitions.iter_mut()
+            .find(|definition| definition.quality.name.eq_ignore_ascii_case(&limits.quality))
+            .ok_or_else(|| format!("Unknown quality: {}", limits.quality))?;
+
+        if definition.set_limits(limits) {
+            changed.push(definition.clone());
+        }
+    }
+
+    if matches.is_present("json") {
+        print_data(matches, &changed);
+    } else {
+        let prefix = if import_matches.is_present("dry-run") { "would update" } else { "updated" };
+
+        for definition in changed.iter() {
+            println!("{} {}", prefix, definition.quality.name);
+        }
+    }
+
+    if !changed.is_empty() && !import_matches.is_present("dry-run") {
+        client.update_quality_definitions(&changed)?;
+    }
+
+    Ok(())
+}
+
+fn parse_size(value: &str, name: &str) -> Result<Option<f64>, Box<dyn Error>> {
+    if value == "unlimited" {
+        return Ok(None);
+    }
+
+    value.parse::<f64>()
+        .map(Some)
+        .map_err(|_| format!("Failed to parse {}: `{}`", name, value).into())
+}
+
+fn is_yaml(file_path: &str) -> bool {
+    file_path.ends_with(".yml") || file_path.ends_with(".yaml")
+}
@@ -0,0 +1,153 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id};
+use super::{confirm, tag_ids};
+
+// the flags shared by `add` and `set`. For `set` anything left out keeps its current value, and
+// terms or tags that are given replace the current ones.
+fn profile_args<'a, 'b>(command: App<'a, 'b>) -> App<'a, 'b> {
+    command
+        .arg(Arg::with_name("name")
+             .help("The name of the release profile")
+             .long("name")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("required")
+             .help("A term the release must contain (at least one of them). Can be given more than once, and /regex/ is supported.")
+             .long("required")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+        .arg(Arg::with_name("ignored")
+             .help("A term the release must not contain. Can be given more than once, and /regex/ is supported.")
+             .long("ignored")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+        .arg(Arg::with_name("indexer")
+             .help("Only apply the profile to releases from the indexer with this ID (0 for all indexers)")
+             .long("indexer")
+             .takes_value(true)
+             )
+        .arg(Arg::with_name("enabled")
+             .help("Whether the profile is used")
+             .long("enabled")
+             .takes_value(true)
+             .possible_values(&["true", "false"])
+             )
+        .arg(Arg::with_name("tag")
+             .help("Only apply the profile to movies with this tag. Can be given more than once, and tags that don't exist are created.")
+             .long("tag")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             )
+}
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("release-profiles")
+        .about("Manage release profiles (required and ignored terms)")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List all release profiles")
+                    )
+        .subcommand(profile_args(SubCommand::with_name("add")
+                    .about("Add a release profile")
+                    ))
+        .subcommand(profile_args(SubCommand::with_name("set")
+                    .about("Change a release profile")
+                    .arg(Arg::with_name("release_profile_id")
+                         .help("The ID of the release profile")
+                         .required(true)
+                         )
+                    ))
+        .subcommand(SubCommand::with_name("delete")
+                    .about("Delete a release profile")
+                    .arg(Arg::with_name("release_profile_id")
+                         .help("The ID of the release profile")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("yes")
+                         .help("Don't ask for confirmation")
+                         .long("yes")
+                         .short("y")
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, rp_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if rp_matches.subcommand_matches("list").is_some() {
+        handle_resp(matches, client.release_profiles()?)?;
+    } else if let Some(add_matches) = rp_matches.subcommand_matches("add") {
+        // send the terms the way the server sends them. With no profiles to go by, newer versions'
+        // lists are used.
+        let joined_terms = client.release_profiles()?.data.iter().any(|profile| profile.joined_terms);
+        let mut profile = radarr::ReleaseProfile { enabled: true, joined_terms, ..Default::default() };
+        update_from_matches(client, &mut profile, add_matches)?;
+
+        if profile.required.is_empty() && profile.ignored.is_empty() {
+            return Err("A release profile needs at least one --required or --ignored term.".into());
+        }
+
+        handle_resp(matches, client.create_release_profile(&profile)?)?;
+    } else if let Some(set_matches) = rp_matches.subcommand_matches("set") {
+        let mut profile = find(client, set_matches)?;
+        update_from_matches(client, &mut profile, set_matches)?;
+
+        handle_resp(matches, client.update_release_profile(&profile)?)?;
+    } else if let Some(del_matches) = rp_matches.subcommand_matches("delete") {
+        let profile = find(client, del_matches)?;
+        let id = profile.id.unwrap_or_default();
+        let name = profile.name.unwrap_or_else(|| id.to_string());
+
+        if !del_matches.is_present("yes") && !confirm(&format!("Delete release profile {}?", name))? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+
+        handle_resp(matches, client.delete_release_profile(id)?)?;
+    } else {
+        return Err("Missing release-profiles subcommand. See `radarr release-profiles --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn find(client: &radarr::Client, matches: &ArgMatches) -> Result<radarr::ReleaseProfile, Box<dyn Error>> {
+    let id = parse_id(matches.value_of("release_profile_id").unwrap(), "release_profile_id")?;
+
+    client.release_profiles()?.data.into_iter()
+        .find(|profile| profile.id == Some(id))
+        .ok_or_else(|| format!("No release profile with ID {}", id).into())
+}
+
+fn update_from_matches(client: &radarr::Client, profile: &mut radarr::ReleaseProfile, matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if let Some(name) = matches.value_of("name") {
+        profile.name = Some(String::from(name));
+    }
+
+    if let Some(terms) = matches.values_of("required") {
+        profile.required = terms.map(String::from).collect();
+    }
+
+    if let Some(terms) = matches.values_of("ignored") {
+        profile.ignored = terms.map(String::from).collect();
+    }
+
+    if let Some(indexer) = matches.value_of("indexer") {
+        profile.indexer_id = parse_id(indexer, "indexer")?;
+    }
+
+    if let Some(enabled) = matches.value_of("enabled") {
+        profile.enabled = enabled == "true";
+    }
+
+    if let Some(labels) = matches.values_of("tag") {
+        profile.tags = tag_ids(client, labels)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id};
+use super::confirm;
+
+// the flags shared by `add` and `set`. For `add` they're all required.
+fn mapping_args<'a, 'b>(command: App<'a, 'b>, required: bool) -> App<'a, 'b> {
+    command
+        .arg(Arg::with_name("host")
+             .help("The host of the download client, as configured in Radarr")
+             .long("host")
+             .takes_value(true)
+             .required(required)
+             )
+        .arg(Arg::with_name("remote_path")
+             .help("The root path the download client reports downloads in")
+             .long("remote-path")
+             .takes_value(true)
+             .required(required)
+             )
+        .arg(Arg::with_name("local_path")
+             .help("The path Radarr should use to reach the remote path")
+             .long("local-path")
+             .takes_value(true)
+             .required(required)
+             )
+}
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("remote-path-mappings")
+        .about("Manage remote path mappings for download clients on other machines")
+        .subcommand(SubCommand::with_name("list")
+                    .about("List all remote path mappings")
+                    )
+        .subcommand(mapping_args(SubCommand::with_name("add")
+                    .about("Add a remote path mapping")
+                    , true))
+        .subcommand(mapping_args(SubCommand::with_name("set")
+                    .about("Change a remote path mapping")
+                    .arg(Arg::with_name("remote_path_mapping_id")
+                         .help("The ID of the remote path mapping")
+                         .required(true)
+                         )
+                    , false))
+        .subcommand(SubCommand::with_name("delete")
+                    .about("Delete a remote path mapping")
+                    .arg(Arg::with_name("remote_path_mapping_id")
+                         .help("The ID of the remote path mapping")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("yes")
+                         .help("Don't ask for confirmation")
+                         .long("yes")
+                         .short("y")
+                         )
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, rpm_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if rpm_matches.subcommand_matches("list").is_some() {
+        handle_resp(matches, client.remote_path_mappings()?)?;
+    } else if let Some(add_matches) = rpm_matches.subcommand_matches("add") {
+        let mapping = radarr::RemotePathMapping {
+            id: None,
+            host: String::from(add_matches.value_of("host").unwrap()),
+            remote_path: String::from(add_matches.value_of("remote_path").unwrap()),
+            local_path: String::from(add_matches.value_of("local_path").unwrap()),
+        };
+
+        handle_resp(matches, client.create_remote_path_mapping(&mapping)?)?;
+    } else if let Some(set_matches) = rpm_matches.subcommand_matches("set") {
+        let mut mapping = find(client, set_matches)?;
+
+        if let Some(host) = set_matches.value_of("host") {
+            mapping.host = String::from(host);
+        }
+
+        if let Some(remote_path) = set_matches.value_of("remote_path") {
+            mapping.remote_path = String::from(remote_path);
+        }
+
+        if let Some(local_path) = set_matches.value_of("local_path") {
+            mapping.local_path = String::from(local_path);
+        }
+
+        handle_resp(matches, client.update_remote_path_mapping(&mapping)?)?;
+    } else if let Some(del_matches) = rpm_matches.subcommand_matches("delete") {
+        let mapping = find(client, del_matches)?;
+        let prompt = format!("Delete the mapping of {}:{} to {}?", mapping.host, mapping.remote_path, mapping.local_path);
+
+        if !del_matches.is_present("yes") && !confirm(&prompt)? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+
+        handle_resp(matches, client.delete_remote_path_mapping(mapping.id.unwrap_or_default())?)?;
+    } else {
+        return Err("Missing remote-path-mappings subcommand. See `radarr remote-path-mappings --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn find(client: &radarr::Client, matches: &ArgMatches) -> Result<radarr::RemotePathMapping, Box<dyn Error>> {
+    let id = parse_id(matches.value_of("remote_path_mapping_id").unwrap(), "remote_path_mapping_id")?;
+
+    client.remote_path_mappings()?.data.into_iter()
+        .find(|mapping| mapping.id == Some(id))
+        .ok_or_else(|| format!("No remote path mapping with ID {}", id).into())
+}
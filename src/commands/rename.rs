@@ -0,0 +1,110 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{parse_id, print_data};
+use super::confirm;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("rename")
+        .about("Preview, and optionally apply, renaming movie files to the current naming format")
+        .arg(Arg::with_name("movie_id")
+             .help("The IDs of the movies to rename")
+             .multiple(true)
+             .required_unless("all")
+             .conflicts_with("all")
+             )
+        .arg(Arg::with_name("all")
+             .help("Rename files for every tracked movie")
+             .long("all")
+             .short("a")
+             )
+        .arg(Arg::with_name("apply")
+             .help("Rename the files after showing the preview")
+             .long("apply")
+             )
+        .arg(Arg::with_name("yes")
+             .help("Don't ask for confirmation before renaming. Only used with --apply.")
+             .long("yes")
+             .short("y")
+             .requires("apply")
+             )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, rename_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let movie_ids: Vec<u32> = if rename_matches.is_present("all") {
+        client.list_movies()?.data.iter().map(|movie| movie.id).collect()
+    } else {
+        rename_matches.values_of("movie_id").unwrap()
+            .map(|id| parse_id(id, "movie_id"))
+            .collect::<Result<_, _>>()?
+    };
+
+    let mut previews = vec![];
+    for movie_id in movie_ids {
+        previews.extend(*client.rename_preview(movie_id)?.data);
+    }
+
+    if matches.is_present("json") {
+        print_data(matches, &previews);
+    } else {
+        print!("{}", render_previews(&previews));
+    }
+
+    if !rename_matches.is_present("apply") || previews.is_empty() {
+        return Ok(());
+    }
+
+    let prompt = format!("Rename {} files?", previews.len());
+    if !rename_matches.is_present("yes") && !confirm(&prompt)? {
+        eprintln!("Aborted.");
+        return Ok(());
+    }
+
+    // Radarr renames per movie, so group the file ids up. Previews come back grouped by movie already.
+    let mut groups: Vec<(u32, Vec<u32>)> = vec![];
+    for preview in previews.iter() {
+        match groups.last_mut() {
+            Some((movie_id, files)) if *movie_id == preview.movie_id => files.push(preview.movie_file_id),
+            _ => groups.push((preview.movie_id, vec![preview.movie_file_id])),
+        }
+    }
+
+    for (movie_id, files) in groups {
+        let resp = client.rename_files(movie_id, &files)?;
+        eprintln!("Queued rename of {} files for movie {} (command {})", files.len(), movie_id, resp.data.id);
+    }
+
+    Ok(())
+}
+
+// each file as a diff of its old and new path
+fn render_previews(previews: &[radarr::RenamePreview]) -> String {
+    if previews.is_empty() {
+        return String::from("Nothing to rename.\n");
+    }
+
+    previews.iter()
+        .map(|preview| format!("movie {} / file {}\n  - {}\n  + {}\n",
+                               preview.movie_id,
+                               preview.movie_file_id,
+                               preview.existing_path,
+                               preview.new_path))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn previews_render_as_a_diff() {
+        let previews: Vec<radarr::RenamePreview> = serde_json::from_value(serde_json::json!([
+            { "movieId": 12, "movieFileId": 34, "existingPath": "Alien.1979.1080p.BluRay.x264.mkv", "newPath": "Alien (1979) Bluray-1080p.mkv" },
+        ])).unwrap();
+
+        assert_eq!(render_previews(&previews),
+                   "movie 12 / file 34\n  - Alien.1979.1080p.BluRay.x264.mkv\n  + Alien (1979) Bluray-1080p.mkv\n");
+        assert_eq!(render_previews(&[]), "Nothing to rename.\n");
+    }
+}
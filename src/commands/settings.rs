@@ -0,0 +1,158 @@
+use std::error::Error;
+
+use clap::{Arg, App, SubCommand, ArgMatches};
+use serde_json::Value;
+
+use crate::{handle_resp, print_data};
+
+const SECTIONS: [&str; 5] = ["naming", "media-management", "host", "ui", "indexer"];
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    let section = Arg::with_name("section")
+        .help("The settings section")
+        .required(true)
+        .possible_values(&SECTIONS);
+
+    SubCommand::with_name("settings")
+        .about("Get and set naming, media management, host, ui and indexer settings")
+        .subcommand(SubCommand::with_name("get")
+                    .about("Show a settings section, or a single key in it")
+                    .arg(section.clone())
+                    .arg(Arg::with_name("key")
+                         .help("The key to show, as named by the api (eg: `copyUsingHardlinks`)")
+                         )
+                    )
+        .subcommand(SubCommand::with_name("set")
+                    .about("Change a single setting")
+                    .arg(section)
+                    .arg(Arg::with_name("key")
+                         .help("The key to change, as named by the api (eg: `copyUsingHardlinks`)")
+                         .required(true)
+                         )
+                    .arg(Arg::with_name("value")
+                         .help("The new value. It's parsed as whatever type the current value is.")
+                         .required(true)
+                         )
+                    )
+        .subcommand(SubCommand::with_name("examples")
+                    .about("Show example file and folder names for the current naming settings")
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, settings_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if let Some(get_matches) = settings_matches.subcommand_matches("get") {
+        // straight from the server, so keys that aren't set show up too
+        let section = get_matches.value_of("section").unwrap();
+        let settings = *client.raw_config(&section.replace('-', ""))?.data;
+
+        match get_matches.value_of("key") {
+            Some(key) => print_data(matches, lookup(&settings, key)?),
+            None => print_data(matches, &settings),
+        }
+    } else if let Some(set_matches) = settings_matches.subcommand_matches("set") {
+        let section = set_matches.value_of("section").unwrap();
+        let key = set_matches.value_of("key").unwrap();
+
+        // the key is looked up in what the server sent, so unset keys can be set too, but only the
+        // typed section is sent back
+        let current = *client.raw_config(&section.replace('-', ""))?.data;
+        let value = coerce(lookup(&current, key)?, set_matches.value_of("value").unwrap())?;
+
+        let mut settings = fetch(client, section)?;
+        settings[key] = value;
+
+        // set back to null, the key is left out of what's sent and what comes back
+        let settings = store(client, section, settings)?;
+        print_data(matches, settings.get(key).unwrap_or(&Value::Null));
+    } else if settings_matches.subcommand_matches("examples").is_some() {
+        let naming = client.naming_config()?;
+        handle_resp(matches, client.naming_examples(&naming.data)?)?;
+    } else {
+        return Err("Missing settings subcommand. See `radarr settings --help`.".into());
+    }
+
+    Ok(())
+}
+
+fn fetch(client: &radarr::Client, section: &str) -> Result<Value, Box<dyn Error>> {
+    let settings = match section {
+        "naming" => serde_json::to_value(&client.naming_config()?.data)?,
+        "media-management" => serde_json::to_value(&client.media_management_config()?.data)?,
+        "host" => serde_json::to_value(&client.host_config()?.data)?,
+        "ui" => serde_json::to_value(&client.ui_config()?.data)?,
+        "indexer" => serde_json::to_value(&client.indexer_config()?.data)?,
+        _ => return Err(format!("Unknown settings section: {}", section).into()),
+    };
+
+    Ok(settings)
+}
+
+fn store(client: &radarr::Client, section: &str, settings: Value) -> Result<Value, Box<dyn Error>> {
+    let settings = match section {
+        "naming" => serde_json::to_value(&client.update_naming_config(&serde_json::from_value(settings)?)?.data)?,
+        "media-management" => serde_json::to_value(&client.update_media_management_config(&serde_json::from_value(settings)?)?.data)?,
+        "host" => serde_json::to_value(&client.update_host_config(&serde_json::from_value(settings)?)?.data)?,
+        "ui" => serde_json::to_value(&client.update_ui_config(&serde_json::from_value(settings)?)?.data)?,
+        "indexer" => serde_json::to_value(&client.update_indexer_config(&serde_json::from_value(settings)?)?.data)?,
+        _ => return Err(format!("Unknown settings section: {}", section).into()),
+    };
+
+    Ok(settings)
+}
+
+fn lookup<'a>(settings: &'a Value, key: &str) -> Result<&'a Value, Box<dyn Error>> {
+    settings.get(key).ok_or_else(|| {
+        let keys: Vec<&String> = settings.as_object().map(|object| object.keys().collect()).unwrap_or_default();
+        let keys: Vec<&str> = keys.iter().map(|key| key.as_str()).collect();
+
+        format!("Unknown key `{}`. Available keys: {}", key, keys.join(", ")).into()
+    })
+}
+
+// parse `raw` as the same type as the setting's current value
+fn coerce(current: &Value, raw: &str) -> Result<Value, Box<dyn Error>> {
+    let value = match current {
+        Value::Bool(_) => match raw.to_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Value::Bool(true),
+            "false" | "no" | "off" | "0" => Value::Bool(false),
+            _ => return Err(format!("Expected true or false, got `{}`", raw).into()),
+        },
+        Value::Number(_) => serde_json::from_str::<serde_json::Number>(raw)
+            .map(Value::Number)
+            .map_err(|_| format!("Expected a number, got `{}`", raw))?,
+        Value::String(_) => Value::String(raw.to_string()),
+        // no type to go on, so take it as json if it parses and a string if not
+        _ => serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string())),
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn coerce_follows_current_type() {
+        assert_eq!(coerce(&json!(false), "yes").unwrap(), json!(true));
+        assert_eq!(coerce(&json!(60), "15").unwrap(), json!(15));
+        assert_eq!(coerce(&json!("smart"), "dash").unwrap(), json!("dash"));
+        assert_eq!(coerce(&json!("{Movie Title}"), "1").unwrap(), json!("1"));
+        assert_eq!(coerce(&Value::Null, "7").unwrap(), json!(7));
+        assert!(coerce(&json!(true), "maybe").is_err());
+        assert!(coerce(&json!(1), "one").is_err());
+    }
+
+    #[test]
+    fn unset_keys_can_be_set_without_sending_the_others() {
+        let current = json!({ "id": 1, "port": 7878, "sslCertPath": null, "certificateValidation": null });
+        let value = coerce(lookup(&current, "sslCertPath").unwrap(), "/certs/radarr.pfx").unwrap();
+
+        let mut settings = serde_json::to_value(serde_json::from_value::<radarr::HostConfig>(current).unwrap()).unwrap();
+        settings["sslCertPath"] = value;
+
+        let sent = serde_json::to_value(serde_json::from_value::<radarr::HostConfig>(settings).unwrap()).unwrap();
+        assert_eq!(sent, json!({ "id": 1, "port": 7878, "sslCertPath": "/certs/radarr.pfx" }));
+    }
+}
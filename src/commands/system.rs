@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use clap::{Arg, App, SubCommand, ArgMatches};
+
+use crate::{handle_resp, parse_id, print_data};
+use super::confirm;
+
+// how long to wait for a restart that can't be seen, before polling for readiness anyway
+const UNSEEN_RESTART_GRACE: Duration = Duration::from_secs(10);
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    let wait_args = [
+        Arg::with_name("timeout")
+            .help("Give up after this many seconds (default: 120)")
+            .long("timeout")
+            .takes_value(true),
+        Arg::with_name("interval")
+            .help("Seconds between status checks (default: 2)")
+            .long("interval")
+            .takes_value(true),
+    ];
+
+    SubCommand::with_name("system")
+        .about("System operations: restart, shutdown, scheduled tasks and updates")
+        .subcommand(SubCommand::with_name("restart")
+                    .about("Restart Radarr")
+                    .arg(Arg::with_name("wait")
+                         .help("Wait until Radarr is back up")
+                         .long("wait")
+                         .short("w")
+                         )
+                    .arg(Arg::with_name("yes")
+                         .help("Don't ask for confirmation")
+                         .long("yes")
+                         .short("y")
+                         )
+                    .args(&wait_args)
+                    )
+        .subcommand(SubCommand::with_name("shutdown")
+                    .about("Shut Radarr down")
+                    .arg(Arg::with_name("yes")
+                         .help("Don't ask for confirmation")
+                         .long("yes")
+                         .short("y")
+                         )
+                    )
+        .subcommand(SubCommand::with_name("tasks")
+                    .about("List scheduled tasks with their interval and last/next execution")
+                    )
+        .subcommand(SubCommand::with_name("updates")
+                    .about("List available versions and their changes")
+                    )
+        .subcommand(SubCommand::with_name("wait-ready")
+                    .about("Poll the server status until Radarr responds (eg: after a restart)")
+                    .args(&wait_args)
+                    )
+}
+
+pub fn run(client: &radarr::Client, matches: &ArgMatches, system_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    if let Some(restart_matches) = system_matches.subcommand_matches("restart") {
+        if !restart_matches.is_present("yes") && !confirm("Restart Radarr?")? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+
+        // the running instance's status, to tell it apart from the restarted one
+        let previous = client.status().ok().map(|status| *status.data);
+        let resp = client.restart()?;
+
+        if restart_matches.is_present("wait") {
+            wait_restarted(client, previous.as_ref(), restart_matches)?;
+            wait_ready(client, matches, restart_matches)?;
+        } else {
+            handle_resp(matches, resp)?;
+        }
+    } else if let Some(shutdown_matches) = system_matches.subcommand_matches("shutdown") {
+        if !shutdown_matches.is_present("yes") && !confirm("Shut Radarr down?")? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+
+        handle_resp(matches, client.shutdown()?)?;
+    } else if system_matches.subcommand_matches("tasks").is_some() {
+        handle_resp(matches, client.tasks()?)?;
+    } else if system_matches.subcommand_matches("updates").is_some() {
+        handle_resp(matches, client.updates()?)?;
+    } else if let Some(wait_matches) = system_matches.subcommand_matches("wait-ready") {
+        wait_ready(client, matches, wait_matches)?;
+    } else {
+        return Err("Missing system subcommand. See `radarr system --help`.".into());
+    }
+
+    Ok(())
+}
+
+// Radarr keeps answering for a moment after being asked to restart, so polling for readiness
+// straight away would find the old process. Wait until it stops answering, or answers as a
+// different instance because it already came back. Versions that don't send a start time can only
+// be told apart when the restart installed an update, so a quick restart on those is assumed once
+// the grace period is over.
+fn wait_restarted(client: &radarr::Client, previous: Option<&radarr::StatusResponse>, wait_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let (timeout, interval) = wait_timing(wait_matches)?;
+    let started = Instant::now();
+    let deadline = started + Duration::from_secs(u64::from(timeout));
+    let has_start_time = previous.is_some_and(|previous| previous.start_time.is_some());
+    let grace = UNSEEN_RESTART_GRACE.min(Duration::from_secs(u64::from(timeout)));
+
+    loop {
+        match client.status() {
+            Err(_) => return Ok(()),
+            Ok(status) if previous.is_some_and(|previous| instance(previous) != instance(&status.data)) => return Ok(()),
+            Ok(_) if !has_start_time && started.elapsed() >= grace => return Ok(()),
+            Ok(_) => {},
+        }
+
+        if Instant::now() >= deadline {
+            return Err(format!("Radarr didn't restart within {} seconds", timeout).into());
+        }
+
+        thread::sleep(Duration::from_secs(u64::from(interval)));
+    }
+}
+
+// what tells one run of Radarr from the next: the start time, or without one the version and
+// build time, which change when a restart installs an update
+fn instance(status: &radarr::StatusResponse) -> (Option<DateTime<Utc>>, &str, DateTime<Utc>) {
+    (status.start_time, &status.version, status.build_time)
+}
+
+fn wait_ready(client: &radarr::Client, matches: &ArgMatches, wait_matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let (timeout, interval) = wait_timing(wait_matches)?;
+    let deadline = Instant::now() + Duration::from_secs(u64::from(timeout));
+
+    loop {
+        match client.status() {
+            Ok(status) => {
+                print_data(matches, &status.data);
+                return Ok(());
+            },
+            Err(error) => {
+                if Instant::now() >= deadline {
+                    return Err(format!("Radarr wasn't ready after {} seconds: {}", timeout, error).into());
+                }
+            },
+        }
+
+        thread::sleep(Duration::from_secs(u64::from(interval)));
+    }
+}
+
+// `--timeout` and `--interval`, in seconds
+fn wait_timing(wait_matches: &ArgMatches) -> Result<(u32, u32), Box<dyn Error>> {
+    let timeout = parse_id(wait_matches.value_of("timeout").unwrap_or("120"), "timeout")?;
+    let interval = parse_id(wait_matches.value_of("interval").unwrap_or("2"), "interval")?;
+
+    Ok((timeout, interval))
+}
@@ -4,12 +4,13 @@ use std::fmt::Debug;
 
 use serde_json::json;
 
-mod radarr;
+
+mod commands;
 
 extern crate clap;
 use clap::{Arg, App, SubCommand, ArgMatches};
 
-// how this should work:
+// how this should work (see `radarr cinema-sync`):
 // iterate over every cinema from alamo
 // filter out all movies of show-type terror tuesday, weird wednesday, video vortext
 // search radarr API
@@ -53,17 +54,41 @@ fn main() {
                     )
         .subcommand(SubCommand::with_name("root-folders")
                     .about("List available root folders")
+                    .subcommand(SubCommand::with_name("add")
+                                .about("Add a root folder")
+                                .arg(Arg::with_name("path")
+                                     .help("The path of the folder on the Radarr server")
+                                     .required(true)
+                                     )
+                                )
+                    .subcommand(SubCommand::with_name("delete")
+                                .about("Delete the root folder with the given ID")
+                                .arg(Arg::with_name("root_folder_id")
+                                     .help("The ID of the root folder to delete")
+                                     .required(true)
+                                     )
+                                )
                     )
         .subcommand(SubCommand::with_name("search")
                     .about("Search for films given a search term")
                     .arg(Arg::with_name("term")
                          .help("The search query")
-                         .required(true)
+                         .required_unless_one(&["tmdb", "imdb"])
+                         .conflicts_with_all(&["tmdb", "imdb"])
+                         )
+                    .arg(Arg::with_name("tmdb")
+                         .help("Look up the film with this TMDb ID instead")
+                         .long("tmdb")
+                         .takes_value(true)
+                         .conflicts_with("imdb")
+                         )
+                    .arg(Arg::with_name("imdb")
+                         .help("Look up the film with this IMDb ID (eg: tt0133093) instead")
+                         .long("imdb")
+                         .takes_value(true)
                          )
                     )
-        .subcommand(SubCommand::with_name("list")
-                    .about("List all movies that are currently tracked")
-                    )
+        .subcommand(commands::list::subcommand())
         .subcommand(SubCommand::with_name("show")
                     .about("Show the movie with the given ID")
                     .arg(Arg::with_name("movie_id")
@@ -71,32 +96,8 @@ fn main() {
                          .required(true)
                          )
                     )
-        .subcommand(SubCommand::with_name("add")
-                    .about("Add the given movie")
-                    .arg(Arg::with_name("file")
-                         .help("Path to file containing search result json")
-                         .long("file")
-                         .short("f")
-                         .takes_value(true)
-                         )
-                    .arg(Arg::with_name("data")
-                         .help("Raw JSON data of search result")
-                         .long("data")
-                         .short("d")
-                         .takes_value(true)
-                         )
-                    .arg(Arg::with_name("no-search")
-                         .help("Do not search for the movie. Just add it.")
-                         .long("no-search")
-                         .takes_value(false)
-                         )
-                    .arg(Arg::with_name("root-folder")
-                         .help("Root folder to download movie. Default is to pick first in available root folders.")
-                         .long("root-folder")
-                         .short("r")
-                         .takes_value(true)
-                         )
-                    )
+        .subcommand(commands::add::subcommand())
+        .subcommand(commands::add_many::subcommand())
         .subcommand(SubCommand::with_name("delete")
                     .about("Delete the movie with the given ID")
                     .arg(Arg::with_name("movie_id")
@@ -109,11 +110,31 @@ fn main() {
                          .long("delete-files")
                          .short("d")
                          )
-                    );
+                    )
+        .subcommand(commands::backup::subcommand())
+        .subcommand(commands::cinema_sync::subcommand())
+        .subcommand(commands::collections::subcommand())
+        .subcommand(commands::config::subcommand())
+        .subcommand(commands::credits::subcommand())
+        .subcommand(commands::custom_formats::subcommand())
+        .subcommand(commands::delay_profiles::subcommand())
+        .subcommand(commands::disk::subcommand())
+        .subcommand(commands::export::subcommand())
+        .subcommand(commands::files::subcommand())
+        .subcommand(commands::import::subcommand())
+        .subcommand(commands::logs::subcommand())
+        .subcommand(commands::person::subcommand())
+        .subcommand(commands::quality_definitions::subcommand())
+        .subcommand(commands::release_profiles::subcommand())
+        .subcommand(commands::remote_path_mappings::subcommand())
+        .subcommand(commands::rename::subcommand())
+        .subcommand(commands::settings::subcommand())
+        .subcommand(commands::system::subcommand());
 
-    if let Err(error) = run(app) {
-        eprintln!("Error: {}", error);
-        process::exit(1);
+    let matches = app.get_matches();
+
+    if let Err(error) = run(&matches) {
+        exit_with_error(&matches, error.as_ref());
     }
 }
 
@@ -128,91 +149,96 @@ fn create_client(matches: &ArgMatches) -> Result<radarr::Client, Box<dyn Error>>
 
 fn config_from_matches(matches: &ArgMatches) -> radarr::Config {
     radarr::Config {
-        api_token: matches.value_of("api-token").map(|v| String::from(v)),
-        protocol: matches.value_of("protocol").map(|v| String::from(v)),
-        hostname: matches.value_of("hostname").map(|v| String::from(v)),
+        api_token: matches.value_of("api-token").map(String::from),
+        protocol: matches.value_of("protocol").map(String::from),
+        hostname: matches.value_of("hostname").map(String::from),
     }
 }
 
-fn run(app: App) -> Result<(), Box<dyn Error>> {
-    let matches = app.get_matches();
-
-    let client = create_client(&matches)?;
+fn run(matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let client = create_client(matches)?;
 
     if let Some(_matches) = matches.subcommand_matches("status") {
-        handle_resp(&matches, client.status()?)?;
+        handle_resp(matches, client.status()?)?;
     } else if let Some(_matches) = matches.subcommand_matches("health") {
-        handle_resp(&matches, client.health()?)?;
-    } else if let Some(_matches) = matches.subcommand_matches("root-folders") {
-        handle_resp(&matches, client.root_folder()?)?;
+        handle_resp(matches, client.health()?)?;
+    } else if let Some(root_folder_matches) = matches.subcommand_matches("root-folders") {
+        if let Some(add_matches) = root_folder_matches.subcommand_matches("add") {
+            handle_resp(matches, client.add_root_folder(add_matches.value_of("path").unwrap())?)?;
+        } else if let Some(del_matches) = root_folder_matches.subcommand_matches("delete") {
+            let root_folder_id = parse_id(del_matches.value_of("root_folder_id").unwrap(), "root_folder_id")?;
+            handle_resp(matches, client.delete_root_folder(root_folder_id)?)?;
+        } else {
+            handle_resp(matches, client.root_folder()?)?;
+        }
     } else if let Some(search_matches) = matches.subcommand_matches("search") {
-        let term = search_matches.value_of("term").unwrap();
-        handle_resp(&matches, client.search(term)?)?;
-    } else if let Some(_matches) = matches.subcommand_matches("list") {
-        handle_resp(&matches, client.list_movies()?)?;
-    } else if let Some(show_matches) = matches.subcommand_matches("show") {
-        if let Ok(movie_id) = show_matches.value_of("movie_id").unwrap().parse::<u32>() {
-            handle_resp(&matches, client.get_movie(movie_id)?)?;
+        if let Some(tmdb_id) = search_matches.value_of("tmdb") {
+            handle_resp(matches, client.lookup_by_tmdb(parse_id(tmdb_id, "tmdb")?)?)?;
+        } else if let Some(imdb_id) = search_matches.value_of("imdb") {
+            handle_resp(matches, client.lookup_by_imdb(imdb_id)?)?;
         } else {
-            // TODO return a proper error
-            eprintln!("Failed to parse movie_id.");
-            process::exit(1);
+            let term = search_matches.value_of("term").unwrap();
+            handle_resp(matches, client.search(term)?)?;
         }
+    } else if let Some(list_matches) = matches.subcommand_matches("list") {
+        commands::list::run(&client, matches, list_matches)?;
+    } else if let Some(show_matches) = matches.subcommand_matches("show") {
+        let movie_id = parse_id(show_matches.value_of("movie_id").unwrap(), "movie_id")?;
+        handle_resp(matches, client.get_movie(movie_id)?)?;
     } else if let Some(add_matches) = matches.subcommand_matches("add") {
-        let data_source = radarr::DataSource::from_matches(&add_matches); //(add_matches.value_of("file"), add_matches.value_of("data"));
-
-        if data_source.is_none() {
-            panic!("Invalid usage");
-        }
-
-        // parse the payload from wherever we're getting it.
-        let data_source = data_source.unwrap();
-        let data = data_source.read()?;
-        let result: radarr::SearchResult = serde_json::from_str(&data)?;
-
-
-        let mut payload = radarr::AddMoviePayload::from_movie_response(&result).unwrap();
-        if ! add_matches.is_present("no-search") {
-            payload.set_search_for_movie(true);
-        }
-
-        let root_folder = 
-            if let Some(root_folder) = add_matches.value_of("root-folder") {
-                root_folder.to_string()
-            } else {
-                let root_folders = client.root_folder()?;
-                root_folders.data[0].path.to_owned()
-            };
-
-        payload.set_root_folder_path(&root_folder);
-
-        let resp = client.add_movie(&payload)?;
-        handle_resp(&matches, resp)?;
-        
+        commands::add::run(&client, matches, add_matches)?;
+    } else if let Some(add_many_matches) = matches.subcommand_matches("add-many") {
+        commands::add_many::run(&client, matches, add_many_matches)?;
     } else if let Some(del_matches) = matches.subcommand_matches("delete") {
-        let delete_files = del_matches.is_present("delete_files");
-
-        if let Ok(movie_id) = del_matches.value_of("movie_id").unwrap().parse::<u32>() {
-            handle_resp(&matches, client.delete_movie(movie_id, delete_files)?)?;
-        } else {
-            // TODO return a proper error
-            eprintln!("Failed to parse movie_id.");
-            process::exit(1);
-        }
+        let movie_id = parse_id(del_matches.value_of("movie_id").unwrap(), "movie_id")?;
+        handle_resp(matches, client.delete_movie(movie_id, del_matches.is_present("delete_files"))?)?;
+    } else if let Some(backup_matches) = matches.subcommand_matches("backup") {
+        commands::backup::run(&client, matches, backup_matches)?;
+    } else if let Some(sync_matches) = matches.subcommand_matches("cinema-sync") {
+        commands::cinema_sync::run(&client, matches, sync_matches)?;
+    } else if let Some(collections_matches) = matches.subcommand_matches("collections") {
+        commands::collections::run(&client, matches, collections_matches)?;
+    } else if let Some(config_matches) = matches.subcommand_matches("config") {
+        commands::config::run(&client, matches, config_matches)?;
+    } else if let Some(credits_matches) = matches.subcommand_matches("credits") {
+        commands::credits::run(&client, matches, credits_matches)?;
+    } else if let Some(cf_matches) = matches.subcommand_matches("custom-formats") {
+        commands::custom_formats::run(&client, matches, cf_matches)?;
+    } else if let Some(dp_matches) = matches.subcommand_matches("delay-profiles") {
+        commands::delay_profiles::run(&client, matches, dp_matches)?;
+    } else if let Some(disk_matches) = matches.subcommand_matches("disk") {
+        commands::disk::run(&client, matches, disk_matches)?;
+    } else if let Some(export_matches) = matches.subcommand_matches("export") {
+        commands::export::run(&client, matches, export_matches)?;
+    } else if let Some(files_matches) = matches.subcommand_matches("files") {
+        commands::files::run(&client, matches, files_matches)?;
+    } else if let Some(import_matches) = matches.subcommand_matches("import") {
+        commands::import::run(&client, matches, import_matches)?;
+    } else if let Some(logs_matches) = matches.subcommand_matches("logs") {
+        commands::logs::run(&client, matches, logs_matches)?;
+    } else if let Some(person_matches) = matches.subcommand_matches("person") {
+        commands::person::run(&client, matches, person_matches)?;
+    } else if let Some(qd_matches) = matches.subcommand_matches("quality-definitions") {
+        commands::quality_definitions::run(&client, matches, qd_matches)?;
+    } else if let Some(rp_matches) = matches.subcommand_matches("release-profiles") {
+        commands::release_profiles::run(&client, matches, rp_matches)?;
+    } else if let Some(rpm_matches) = matches.subcommand_matches("remote-path-mappings") {
+        commands::remote_path_mappings::run(&client, matches, rpm_matches)?;
+    } else if let Some(rename_matches) = matches.subcommand_matches("rename") {
+        commands::rename::run(&client, matches, rename_matches)?;
+    } else if let Some(settings_matches) = matches.subcommand_matches("settings") {
+        commands::settings::run(&client, matches, settings_matches)?;
+    } else if let Some(system_matches) = matches.subcommand_matches("system") {
+        commands::system::run(&client, matches, system_matches)?;
     } else {
-        panic!("Unhandled subcommand. No bueno.")
+        return Err(format!("Unhandled subcommand: {}", matches.subcommand_name().unwrap_or("none")).into());
     }
 
     Ok(())
 }
 
 fn handle_resp<T: Debug + serde::Serialize>(matches: &ArgMatches, resp: radarr::Response<T>) -> Result<(), Box<dyn Error>> {
-    if matches.is_present("json") {
-        // let json = serde_json::to_string(&resp.data)?;
-        println!("{}", json!(&resp.data));
-    } else {
-        println!("{:#?}", &resp.data);
-    }
+    print_data(matches, &resp.data);
 
     // exit non-zero if there was any error
     if resp.api_response.status().is_server_error() {
@@ -224,3 +250,50 @@ fn handle_resp<T: Debug + serde::Serialize>(matches: &ArgMatches, resp: radarr::
     process::exit(0);
 }
 
+
+// exit codes besides the ones `handle_resp` uses for server (1) and client (2) errors
+const EXIT_ALREADY_EXISTS: i32 = 3;
+
+// report an error, as json with `--json`, and exit with a code scripts can tell apart
+fn exit_with_error(matches: &ArgMatches, error: &(dyn Error + 'static)) -> ! {
+    let (kind, code, details) = if let Some(error) = error.downcast_ref::<radarr::error::MovieExists>() {
+        ("already_exists", EXIT_ALREADY_EXISTS, json!({ "tmdbId": error.tmdb_id, "movieId": error.movie_id }))
+    } else if let Some(error) = error.downcast_ref::<radarr::error::UnableToAddMovie>() {
+        ("unable_to_add_movie", 1, json!({ "status": error.status(), "failures": error.failures() }))
+    } else if let Some(error) = error.downcast_ref::<radarr::error::ApiError>() {
+        ("api_error", 1, json!({ "status": error.status }))
+    } else if error.downcast_ref::<radarr::error::NoRootFolder>().is_some() {
+        ("no_root_folder", 1, json!({}))
+    } else if error.downcast_ref::<radarr::error::ConfigNotMaterializeable>().is_some() {
+        ("config", 1, json!({}))
+    } else {
+        ("error", 1, json!({}))
+    };
+
+    if matches.is_present("json") {
+        let mut output = json!({ "error": kind, "message": error.to_string() });
+
+        if let (Some(output), Some(details)) = (output.as_object_mut(), details.as_object()) {
+            output.extend(details.clone());
+        }
+
+        eprintln!("{}", output);
+    } else {
+        eprintln!("Error: {}", error);
+    }
+
+    process::exit(code);
+}
+
+fn print_data<T: Debug + serde::Serialize>(matches: &ArgMatches, data: &T) {
+    if matches.is_present("json") {
+        println!("{}", json!(data));
+    } else {
+        println!("{:#?}", data);
+    }
+}
+
+fn parse_id(value: &str, name: &str) -> Result<u32, Box<dyn Error>> {
+    value.parse::<u32>()
+        .map_err(|_| format!("Failed to parse {}: `{}`", name, value).into())
+}
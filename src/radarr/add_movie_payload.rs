@@ -1,8 +1,13 @@
 use serde::{Serialize, Deserialize};
+use serde_json::json;
 
 use super::search_result::SearchResult;
+use super::collection::CollectionMovie;
 use super::image::Image;
 use super::add_options::AddOptions;
+use super::enums::{MinimumAvailability, MonitorMode};
+use super::error::{self, ValidationFailure};
+
 // Required:
 
 // title (string)
@@ -44,14 +49,23 @@ pub struct AddMoviePayload {
 
     pub monitored: Option<bool>,
 
+    #[serde(rename = "minimumAvailability", skip_serializing_if = "Option::is_none")]
+    pub minimum_availability: Option<MinimumAvailability>,
+
     #[serde(rename = "addOptions")]
     pub add_options: Option<AddOptions>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<u32>,
 }
 
 impl AddMoviePayload {
-    pub fn from_movie_response(movie: &SearchResult) -> Option<AddMoviePayload> {
-        if movie.movie_file.is_some() || movie.monitored {
-            return None;
+    // a search result for a movie that's already in the library can't be added again. Only the id
+    // says that: lookups can come back monitored or with a file for movies Radarr doesn't track.
+    // Monitoring is left for the builder to set.
+    pub fn from_movie_response(movie: &SearchResult) -> Result<AddMoviePayload, error::MovieExists> {
+        if movie.is_existing() {
+            return Err(error::MovieExists::new(&movie.title, movie.tmdb_id, movie.id.filter(|id| *id != 0)));
         }
 
         let quality_profile_id = movie.quality_profile_id;
@@ -65,7 +79,7 @@ impl AddMoviePayload {
         let monitored = None;
         let add_options = None;
 
-        Some(AddMoviePayload {
+        Ok(AddMoviePayload {
             title,
             title_slug,
             quality_profile_id,
@@ -75,17 +89,52 @@ impl AddMoviePayload {
             path,
             root_folder_path,
             monitored,
+            minimum_availability: None,
             add_options,
+            tags: vec![],
         })
     }
 
+    // collection members aren't full lookups, so the quality profile has to be picked by the caller
+    pub fn from_collection_movie(movie: &CollectionMovie, quality_profile_id: u32) -> AddMoviePayload {
+        AddMoviePayload {
+            title: movie.title.to_owned(),
+            // newer versions use the tmdb id as the slug
+            title_slug: movie.tmdb_id.to_string(),
+            quality_profile_id,
+            images: movie.images.to_vec(),
+            tmdb_id: movie.tmdb_id,
+            year: movie.year,
+            path: None,
+            root_folder_path: None,
+            monitored: None,
+            minimum_availability: None,
+            add_options: None,
+            tags: vec![],
+        }
+    }
+
     pub fn set_search_for_movie(&mut self, value: bool) {
-        let search_object = AddOptions {
-            search_for_movie: value,
-            monitor: "movieOnly".into(),
-        };
+        self.add_options_mut().search_for_movie = value;
+    }
+
+    pub fn set_monitor(&mut self, value: MonitorMode) {
+        self.add_options_mut().monitor = value;
+    }
 
-        self.add_options = Some(search_object);
+    pub fn set_tags(&mut self, value: Vec<u32>) {
+        self.tags = value;
+    }
+
+    fn add_options_mut(&mut self) -> &mut AddOptions {
+        self.add_options.get_or_insert(AddOptions {
+            search_for_movie: false,
+            monitor: MonitorMode::MovieOnly,
+        })
+    }
+
+    pub fn set_quality_profile_id(&mut self, value: u32) {
+        self.quality_profile_id = value;
     }
 
     pub fn set_monitored(&mut self, value: bool) {
@@ -100,7 +149,200 @@ impl AddMoviePayload {
         self.root_folder_path = Some(String::from(value));
     }
 
+    pub fn set_minimum_availability(&mut self, value: MinimumAvailability) {
+        self.minimum_availability = Some(value);
+    }
+
+    pub fn with_quality_profile_id(mut self, value: u32) -> AddMoviePayload {
+        self.set_quality_profile_id(value);
+        self
+    }
+
+    pub fn with_monitor(mut self, value: MonitorMode) -> AddMoviePayload {
+        self.set_monitor(value);
+        self
+    }
+
+    pub fn with_monitored(mut self, value: bool) -> AddMoviePayload {
+        self.set_monitored(value);
+        self
+    }
+
+    pub fn with_minimum_availability(mut self, value: MinimumAvailability) -> AddMoviePayload {
+        self.set_minimum_availability(value);
+        self
+    }
+
+    pub fn with_search_for_movie(mut self, value: bool) -> AddMoviePayload {
+        self.set_search_for_movie(value);
+        self
+    }
+
+    pub fn with_tags(mut self, value: Vec<u32>) -> AddMoviePayload {
+        self.set_tags(value);
+        self
+    }
+
+    pub fn with_path(mut self, value: &str) -> AddMoviePayload {
+        self.set_path(value);
+        self
+    }
+
+    pub fn with_root_folder_path(mut self, value: &str) -> AddMoviePayload {
+        self.set_root_folder_path(value);
+        self
+    }
+
     pub fn is_valid(&self) -> bool {
         self.path.is_some() || self.root_folder_path.is_some()
     }
+
+    // catch what Radarr would reject, and combinations that contradict each other, before sending
+    pub fn validate(&self) -> Result<(), error::UnableToAddMovie> {
+        let mut failures = vec![];
+
+        match (&self.path, &self.root_folder_path) {
+            (None, None) => failures.push(ValidationFailure::new("path", "Either a path or a root folder is needed", json!(null))),
+            (Some(path), Some(_)) => failures.push(ValidationFailure::new("path", "A path and a root folder can't both be given", json!(path))),
+            _ => {},
+        }
+
+        if self.quality_profile_id == 0 {
+            failures.push(ValidationFailure::new("qualityProfileId", "A quality profile is needed", json!(0)));
+        }
+
+        if let Some(minimum_availability) = &self.minimum_availability {
+            if !minimum_availability.is_selectable() {
+                let message = format!("Must be one of {}", MinimumAvailability::SELECTABLE.join(", "));
+                failures.push(ValidationFailure::new("minimumAvailability", &message, json!(minimum_availability)));
+            }
+        }
+
+        if let Some(add_options) = &self.add_options {
+            let monitor = &add_options.monitor;
+
+            if !monitor.is_known() {
+                let message = format!("Must be one of {}", MonitorMode::VALUES.join(", "));
+                failures.push(ValidationFailure::new("addOptions.monitor", &message, json!(monitor)));
+            } else if self.monitored == Some(false) && *monitor != MonitorMode::None {
+                failures.push(ValidationFailure::new("addOptions.monitor", "An unmonitored movie can only be added with monitor `none`", json!(monitor)));
+            } else if self.monitored == Some(true) && *monitor == MonitorMode::None {
+                failures.push(ValidationFailure::new("addOptions.monitor", "Monitor `none` adds the movie unmonitored", json!(monitor)));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(error::UnableToAddMovie::with_failures(failures))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> AddMoviePayload {
+        AddMoviePayload {
+            title: String::from("Alien"),
+            title_slug: String::from("348"),
+            quality_profile_id: 0,
+            images: vec![],
+            tmdb_id: 348,
+            year: 1979,
+            path: None,
+            root_folder_path: None,
+            monitored: None,
+            minimum_availability: None,
+            add_options: None,
+            tags: vec![],
+        }
+    }
+
+    fn search_result(id: u32, monitored: bool) -> SearchResult {
+        serde_json::from_value(json!({
+            "title": "Alien",
+            "secondaryYearSourceId": 0,
+            "sortTitle": "alien",
+            "alternateTitles": [],
+            "status": "released",
+            "overview": "",
+            "year": 1979,
+            "monitored": monitored,
+            "minimumAvailability": "released",
+            "isAvailable": true,
+            "folderName": "",
+            "runtime": 117,
+            "tmdbId": 348,
+            "titleSlug": "348",
+            "genres": [],
+            "added": "0001-01-01T00:00:00Z",
+            "qualityProfileId": 0,
+            "images": [],
+            "id": id,
+        })).unwrap()
+    }
+
+    fn failed_properties(payload: &AddMoviePayload) -> Vec<String> {
+        match payload.validate() {
+            Ok(()) => vec![],
+            Err(err) => err.failures().iter().map(|failure| failure.property_name.to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    fn validate_accepts_a_complete_payload() {
+        let payload = payload()
+            .with_quality_profile_id(1)
+            .with_root_folder_path("/movies")
+            .with_monitor(MonitorMode::MovieAndCollection)
+            .with_minimum_availability(MinimumAvailability::Released)
+            .with_search_for_movie(true)
+            .with_tags(vec![1, 2]);
+
+        assert!(payload.validate().is_ok());
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["addOptions"], json!({ "searchForMovie": true, "monitor": "movieAndCollection" }));
+        assert_eq!(json["minimumAvailability"], "released");
+        assert_eq!(json["tags"], json!([1, 2]));
+    }
+
+    #[test]
+    fn validate_reports_every_problem() {
+        assert_eq!(failed_properties(&payload()), vec!["path", "qualityProfileId"]);
+
+        let payload = payload()
+            .with_quality_profile_id(1)
+            .with_path("/movies/Alien (1979)")
+            .with_root_folder_path("/movies")
+            .with_minimum_availability(MinimumAvailability::PreDb)
+            .with_monitor(MonitorMode::Unknown(String::from("everything")));
+
+        assert_eq!(failed_properties(&payload), vec!["path", "minimumAvailability", "addOptions.monitor"]);
+    }
+
+    #[test]
+    fn validate_rejects_contradicting_monitoring() {
+        let base = payload().with_quality_profile_id(1).with_root_folder_path("/movies");
+
+        let unmonitored = base.with_monitored(false).with_monitor(MonitorMode::MovieOnly);
+        assert_eq!(failed_properties(&unmonitored), vec!["addOptions.monitor"]);
+
+        let unmonitored = unmonitored.with_monitor(MonitorMode::None);
+        assert!(unmonitored.validate().is_ok());
+
+        let monitored = unmonitored.with_monitored(true);
+        assert_eq!(failed_properties(&monitored), vec!["addOptions.monitor"]);
+    }
+
+    #[test]
+    fn only_tracked_search_results_exist() {
+        let payload = AddMoviePayload::from_movie_response(&search_result(0, true)).unwrap();
+        assert_eq!(payload.monitored, None);
+
+        let err = AddMoviePayload::from_movie_response(&search_result(7, false)).unwrap_err();
+        assert_eq!(err.movie_id, Some(7));
+    }
 }
@@ -1,9 +1,11 @@
 use serde::{Serialize, Deserialize};
 
+use super::enums::MonitorMode;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AddOptions {
     #[serde(rename = "searchForMovie")]
     pub search_for_movie: bool,
 
-    pub monitor: String,
+    pub monitor: MonitorMode,
 }
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+// id: 3
+// name: "radarr_backup_v3.2.2.5080_2021.06.27_04.12.00.zip"
+// path: "/backup/scheduled/radarr_backup_v3.2.2.5080_2021.06.27_04.12.00.zip"
+// type: "scheduled"
+// size: 1802240
+// time: "2021-06-27T04:12:00Z"
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Backup {
+    pub id: u32,
+    pub name: String,
+
+    // relative to the server's url base, not the api
+    pub path: String,
+
+    #[serde(rename = "type")]
+    pub backup_type: String,
+
+    pub size: Option<u64>,
+
+    #[serde(with = "super::datetime")]
+    pub time: DateTime<Utc>,
+}
@@ -1,16 +1,49 @@
-use reqwest;
 extern crate url;
 use url::form_urlencoded;
 
 use std::error::Error;
+use std::fmt::Debug;
+use std::io::Write;
+use std::path::Path;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
 
 use super::config;
 use super::search_result::SearchResult;
 use super::status_response::StatusResponse;
 use super::health_response::HealthResponse;
+use super::system_task::SystemTask;
+use super::update_response::UpdateResponse;
 use super::root_folder_response::RootFolderResponse;
+use super::disk_space::DiskSpace;
 use super::movie_response::MovieResponse;
 use super::add_movie_payload::AddMoviePayload;
+use super::movie_file::MovieFile;
+use super::movie_file_editor_payload::MovieFileEditorPayload;
+use super::language::Language;
+use super::quality_definition::QualityDefinition;
+use super::custom_format::CustomFormat;
+use super::quality_profile::QualityProfile;
+use super::provider::Provider;
+use super::tag::Tag;
+use super::collection::Collection;
+use super::credit::Credit;
+use super::delay_profile::DelayProfile;
+use super::release_profile::ReleaseProfile;
+use super::remote_path_mapping::RemotePathMapping;
+use super::naming_config::{NamingConfig, NamingExamples};
+use super::media_management_config::MediaManagementConfig;
+use super::host_config::HostConfig;
+use super::ui_config::UiConfig;
+use super::indexer_config::IndexerConfig;
+use super::command_response::CommandResponse;
+use super::backup::Backup;
+use super::page::Page;
+use super::log_entry::{LogEntry, LogFile};
+use super::manual_import::{ManualImportItem, ManualImportPayload};
+use super::rename_preview::{RenamePreview, RenameFilesPayload};
 use super::response::Response;
 use super::error;
 
@@ -41,6 +74,16 @@ impl Client {
         Ok(Response::new(resp, results))
     }
 
+    pub fn lookup_by_tmdb(&self, tmdb_id: u32) -> Result<Response<SearchResult>, Box<dyn Error>> {
+        let tmdb_id = tmdb_id.to_string();
+
+        self.get("movie/lookup/tmdb", &[("tmdbId", &tmdb_id)])
+    }
+
+    pub fn lookup_by_imdb(&self, imdb_id: &str) -> Result<Response<SearchResult>, Box<dyn Error>> {
+        self.get("movie/lookup/imdb", &[("imdbId", imdb_id)])
+    }
+
     pub fn status(&self) -> Result<Response<StatusResponse>, Box<dyn Error>> {
         let query_string: String = form_urlencoded::Serializer::new(String::new())
             .append_pair("apikey", &self.config.api_token)
@@ -69,6 +112,22 @@ impl Client {
         Ok(Response::new(resp, health))
     }
 
+    pub fn restart(&self) -> Result<Response<serde_json::Value>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "system/restart", &[], None::<&()>)
+    }
+
+    pub fn shutdown(&self) -> Result<Response<serde_json::Value>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "system/shutdown", &[], None::<&()>)
+    }
+
+    pub fn tasks(&self) -> Result<Response<Vec<SystemTask>>, Box<dyn Error>> {
+        self.get("system/task", &[])
+    }
+
+    pub fn updates(&self) -> Result<Response<Vec<UpdateResponse>>, Box<dyn Error>> {
+        self.get("update", &[])
+    }
+
     pub fn root_folder(&self) -> Result<Response<Vec<RootFolderResponse>>, Box<dyn Error>> {
         let query_string: String = form_urlencoded::Serializer::new(String::new())
             .append_pair("apikey", &self.config.api_token)
@@ -83,6 +142,20 @@ impl Client {
         Ok(Response::new(resp, root_folder))
     }
 
+    pub fn add_root_folder(&self, path: &str) -> Result<Response<RootFolderResponse>, Box<dyn Error>> {
+        let payload = json!({ "path": path });
+
+        self.send(reqwest::Method::POST, "rootfolder", &[], Some(&payload))
+    }
+
+    pub fn delete_root_folder(&self, id: u32) -> Result<Response<()>, Box<dyn Error>> {
+        self.send(reqwest::Method::DELETE, &format!("rootfolder/{}", id), &[], None::<&()>)
+    }
+
+    pub fn disk_space(&self) -> Result<Response<Vec<DiskSpace>>, Box<dyn Error>> {
+        self.get("diskspace", &[])
+    }
+
     pub fn list_movies(&self) -> Result<Response<Vec<MovieResponse>>, Box<dyn Error>> {
         let query_string: String = form_urlencoded::Serializer::new(String::new())
             .append_pair("apikey", &self.config.api_token)
@@ -115,6 +188,8 @@ impl Client {
     }
 
     pub fn add_movie(&self, movie: &AddMoviePayload) -> Result<Response<String>, Box<dyn Error>> {
+        movie.validate()?;
+
         let query_string: String = form_urlencoded::Serializer::new(String::new())
             .append_pair("apikey", &self.config.api_token)
             .finish();
@@ -130,19 +205,27 @@ impl Client {
             .header("content-type", "application/json")
             .send()?;
 
+        let body = resp.text()?;
+
         if resp.status().is_success() {
-            let body = resp.text()?;
             Ok(Response::new(resp, body))
         } else {
-            let body = resp.text()?;
-            eprintln!("[{}] error body: {body}", resp.status());
-            // FIXME this should actually percolate up an error
-            Err(Box::new(error::UnableToAddMovie::with_msg("Unable to add movie")))
+            Err(Box::new(error::UnableToAddMovie::from_response(resp.status().as_u16(), &body)))
         }
     }
 
+    // the tracked movie with this TMDb id, if there is one
+    pub fn find_movie_by_tmdb(&self, tmdb_id: u32) -> Result<Option<MovieResponse>, Box<dyn Error>> {
+        let tmdb_param = tmdb_id.to_string();
+        let movies: Response<Vec<MovieResponse>> = self.get("movie", &[("tmdbId", &tmdb_param)])?;
+
+        // older versions ignore the filter and send back every movie
+        Ok(movies.data.into_iter().find(|movie| movie.tmdb_id == Some(tmdb_id)))
+    }
+
     pub fn delete_movie(&self, movie_id: u32, delete_files: bool) -> Result<Response<()>, Box<dyn Error>> {
         let query_string: String = form_urlencoded::Serializer::new(String::new())
+            .append_pair("deleteFiles", &delete_files.to_string())
             .append_pair("apikey", &self.config.api_token)
             .finish();
         
@@ -152,12 +235,400 @@ impl Client {
 
         let mut resp = client.delete(&url).send()?;
 
-        if resp.status().is_success() {
-            Ok(Response::new(resp, ()))
-        } else {
+        if !resp.status().is_success() {
             let body = resp.text()?;
-            panic!("Failed to delete movie: {}", body);
+            return Err(Box::new(error::ApiError::new(resp.status().as_u16(), &body)));
         }
+
+        Ok(Response::new(resp, ()))
+    }
+
+    pub fn movie_files(&self, movie_id: u32) -> Result<Response<Vec<MovieFile>>, Box<dyn Error>> {
+        let movie_id = movie_id.to_string();
+
+        self.get("moviefile", &[("movieId", &movie_id)])
+    }
+
+    pub fn get_movie_file(&self, id: u32) -> Result<Response<MovieFile>, Box<dyn Error>> {
+        self.get(&format!("moviefile/{}", id), &[])
+    }
+
+    pub fn delete_movie_file(&self, id: u32) -> Result<Response<()>, Box<dyn Error>> {
+        self.send(reqwest::Method::DELETE, &format!("moviefile/{}", id), &[], None::<&()>)
+    }
+
+    pub fn delete_movie_files(&self, ids: &[u32]) -> Result<Response<()>, Box<dyn Error>> {
+        let payload = json!({ "movieFileIds": ids });
+
+        self.send(reqwest::Method::DELETE, "moviefile/bulk", &[], Some(&payload))
+    }
+
+    pub fn edit_movie_files(&self, payload: &MovieFileEditorPayload) -> Result<Response<Vec<MovieFile>>, Box<dyn Error>> {
+        self.send(reqwest::Method::PUT, "moviefile/editor", &[], Some(payload))
+    }
+
+    pub fn languages(&self) -> Result<Response<Vec<Language>>, Box<dyn Error>> {
+        self.get("language", &[])
+    }
+
+    pub fn quality_definitions(&self) -> Result<Response<Vec<QualityDefinition>>, Box<dyn Error>> {
+        self.get("qualitydefinition", &[])
+    }
+
+    pub fn update_quality_definition(&self, definition: &QualityDefinition) -> Result<Response<QualityDefinition>, Box<dyn Error>> {
+        self.send(reqwest::Method::PUT, &format!("qualitydefinition/{}", definition.id), &[], Some(definition))
+    }
+
+    pub fn update_quality_definitions(&self, definitions: &[QualityDefinition]) -> Result<Response<Vec<QualityDefinition>>, Box<dyn Error>> {
+        self.send(reqwest::Method::PUT, "qualitydefinition/update", &[], Some(definitions))
+    }
+
+    pub fn custom_formats(&self) -> Result<Response<Vec<CustomFormat>>, Box<dyn Error>> {
+        self.get("customformat", &[])
+    }
+
+    pub fn get_custom_format(&self, id: u32) -> Result<Response<CustomFormat>, Box<dyn Error>> {
+        self.get(&format!("customformat/{}", id), &[])
+    }
+
+    pub fn create_custom_format(&self, custom_format: &CustomFormat) -> Result<Response<CustomFormat>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "customformat", &[], Some(custom_format))
+    }
+
+    // update the custom format with `custom_format.id`
+    pub fn update_custom_format(&self, custom_format: &CustomFormat) -> Result<Response<CustomFormat>, Box<dyn Error>> {
+        let id = custom_format.id.ok_or("custom format has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("customformat/{}", id), &[], Some(custom_format))
+    }
+
+    pub fn delete_custom_format(&self, id: u32) -> Result<Response<()>, Box<dyn Error>> {
+        self.send(reqwest::Method::DELETE, &format!("customformat/{}", id), &[], None::<&()>)
+    }
+
+    pub fn quality_profiles(&self) -> Result<Response<Vec<QualityProfile>>, Box<dyn Error>> {
+        self.get("qualityprofile", &[])
+    }
+
+    pub fn create_quality_profile(&self, profile: &QualityProfile) -> Result<Response<QualityProfile>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "qualityprofile", &[], Some(profile))
+    }
+
+    // update the quality profile with `profile.id`
+    pub fn update_quality_profile(&self, profile: &QualityProfile) -> Result<Response<QualityProfile>, Box<dyn Error>> {
+        let id = profile.id.ok_or("quality profile has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("qualityprofile/{}", id), &[], Some(profile))
+    }
+
+    pub fn tags(&self) -> Result<Response<Vec<Tag>>, Box<dyn Error>> {
+        self.get("tag", &[])
+    }
+
+    pub fn create_tag(&self, label: &str) -> Result<Response<Tag>, Box<dyn Error>> {
+        let payload = json!({ "label": label });
+
+        self.send(reqwest::Method::POST, "tag", &[], Some(&payload))
+    }
+
+    pub fn indexers(&self) -> Result<Response<Vec<Provider>>, Box<dyn Error>> {
+        self.get("indexer", &[])
+    }
+
+    pub fn create_indexer(&self, indexer: &Provider) -> Result<Response<Provider>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "indexer", &[], Some(indexer))
+    }
+
+    pub fn update_indexer(&self, indexer: &Provider) -> Result<Response<Provider>, Box<dyn Error>> {
+        let id = indexer.id.ok_or("indexer has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("indexer/{}", id), &[], Some(indexer))
+    }
+
+    pub fn download_clients(&self) -> Result<Response<Vec<Provider>>, Box<dyn Error>> {
+        self.get("downloadclient", &[])
+    }
+
+    pub fn create_download_client(&self, download_client: &Provider) -> Result<Response<Provider>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "downloadclient", &[], Some(download_client))
+    }
+
+    pub fn update_download_client(&self, download_client: &Provider) -> Result<Response<Provider>, Box<dyn Error>> {
+        let id = download_client.id.ok_or("download client has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("downloadclient/{}", id), &[], Some(download_client))
+    }
+
+    pub fn notifications(&self) -> Result<Response<Vec<Provider>>, Box<dyn Error>> {
+        self.get("notification", &[])
+    }
+
+    pub fn create_notification(&self, notification: &Provider) -> Result<Response<Provider>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "notification", &[], Some(notification))
+    }
+
+    pub fn update_notification(&self, notification: &Provider) -> Result<Response<Provider>, Box<dyn Error>> {
+        let id = notification.id.ok_or("notification has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("notification/{}", id), &[], Some(notification))
+    }
+
+    pub fn collections(&self) -> Result<Response<Vec<Collection>>, Box<dyn Error>> {
+        self.get("collection", &[])
+    }
+
+    pub fn get_collection(&self, id: u32) -> Result<Response<Collection>, Box<dyn Error>> {
+        self.get(&format!("collection/{}", id), &[])
+    }
+
+    pub fn update_collection(&self, collection: &Collection) -> Result<Response<Collection>, Box<dyn Error>> {
+        self.send(reqwest::Method::PUT, &format!("collection/{}", collection.id), &[], Some(collection))
+    }
+
+    pub fn credits(&self, movie_id: u32) -> Result<Response<Vec<Credit>>, Box<dyn Error>> {
+        let movie_id = movie_id.to_string();
+
+        self.get("credit", &[("movieId", &movie_id)])
+    }
+
+    pub fn delay_profiles(&self) -> Result<Response<Vec<DelayProfile>>, Box<dyn Error>> {
+        self.get("delayprofile", &[])
+    }
+
+    pub fn create_delay_profile(&self, profile: &DelayProfile) -> Result<Response<DelayProfile>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "delayprofile", &[], Some(profile))
+    }
+
+    pub fn update_delay_profile(&self, profile: &DelayProfile) -> Result<Response<DelayProfile>, Box<dyn Error>> {
+        let id = profile.id.ok_or("delay profile has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("delayprofile/{}", id), &[], Some(profile))
+    }
+
+    pub fn delete_delay_profile(&self, id: u32) -> Result<Response<()>, Box<dyn Error>> {
+        self.send(reqwest::Method::DELETE, &format!("delayprofile/{}", id), &[], None::<&()>)
+    }
+
+    // move a delay profile to just after `after_id`, or to the top when that's `None`
+    pub fn reorder_delay_profile(&self, id: u32, after_id: Option<u32>) -> Result<Response<Vec<DelayProfile>>, Box<dyn Error>> {
+        let after_id = after_id.map(|after_id| after_id.to_string());
+        let params: Vec<(&str, &str)> = after_id.iter().map(|after_id| ("afterId", after_id.as_str())).collect();
+
+        self.send(reqwest::Method::PUT, &format!("delayprofile/reorder/{}", id), &params, None::<&()>)
+    }
+
+    pub fn release_profiles(&self) -> Result<Response<Vec<ReleaseProfile>>, Box<dyn Error>> {
+        self.get("releaseprofile", &[])
+    }
+
+    pub fn create_release_profile(&self, profile: &ReleaseProfile) -> Result<Response<ReleaseProfile>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "releaseprofile", &[], Some(profile))
+    }
+
+    pub fn update_release_profile(&self, profile: &ReleaseProfile) -> Result<Response<ReleaseProfile>, Box<dyn Error>> {
+        let id = profile.id.ok_or("release profile has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("releaseprofile/{}", id), &[], Some(profile))
+    }
+
+    pub fn delete_release_profile(&self, id: u32) -> Result<Response<()>, Box<dyn Error>> {
+        self.send(reqwest::Method::DELETE, &format!("releaseprofile/{}", id), &[], None::<&()>)
+    }
+
+    pub fn remote_path_mappings(&self) -> Result<Response<Vec<RemotePathMapping>>, Box<dyn Error>> {
+        self.get("remotepathmapping", &[])
+    }
+
+    pub fn create_remote_path_mapping(&self, mapping: &RemotePathMapping) -> Result<Response<RemotePathMapping>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "remotepathmapping", &[], Some(mapping))
+    }
+
+    pub fn update_remote_path_mapping(&self, mapping: &RemotePathMapping) -> Result<Response<RemotePathMapping>, Box<dyn Error>> {
+        let id = mapping.id.ok_or("remote path mapping has no id")?;
+
+        self.send(reqwest::Method::PUT, &format!("remotepathmapping/{}", id), &[], Some(mapping))
+    }
+
+    pub fn delete_remote_path_mapping(&self, id: u32) -> Result<Response<()>, Box<dyn Error>> {
+        self.send(reqwest::Method::DELETE, &format!("remotepathmapping/{}", id), &[], None::<&()>)
+    }
+
+    pub fn naming_config(&self) -> Result<Response<NamingConfig>, Box<dyn Error>> {
+        self.get("config/naming", &[])
+    }
+
+    pub fn update_naming_config(&self, config: &NamingConfig) -> Result<Response<NamingConfig>, Box<dyn Error>> {
+        self.update_config("naming", config.id, config)
+    }
+
+    // example file and folder names, rendered using the given naming settings
+    pub fn naming_examples(&self, config: &NamingConfig) -> Result<Response<NamingExamples>, Box<dyn Error>> {
+        let settings = serde_json::to_value(config)?;
+        let mut params: Vec<(String, String)> = vec![];
+
+        if let Some(settings) = settings.as_object() {
+            for (key, value) in settings {
+                match value {
+                    serde_json::Value::String(value) => params.push((key.to_owned(), value.to_owned())),
+                    serde_json::Value::Bool(_) | serde_json::Value::Number(_) => params.push((key.to_owned(), value.to_string())),
+                    _ => {},
+                }
+            }
+        }
+
+        let params: Vec<(&str, &str)> = params.iter().map(|(key, value)| (key.as_str(), value.as_str())).collect();
+
+        self.get("config/naming/examples", &params)
+    }
+
+    pub fn media_management_config(&self) -> Result<Response<MediaManagementConfig>, Box<dyn Error>> {
+        self.get("config/mediamanagement", &[])
+    }
+
+    pub fn update_media_management_config(&self, config: &MediaManagementConfig) -> Result<Response<MediaManagementConfig>, Box<dyn Error>> {
+        self.update_config("mediamanagement", config.id, config)
+    }
+
+    pub fn host_config(&self) -> Result<Response<HostConfig>, Box<dyn Error>> {
+        self.get("config/host", &[])
+    }
+
+    pub fn update_host_config(&self, config: &HostConfig) -> Result<Response<HostConfig>, Box<dyn Error>> {
+        self.update_config("host", config.id, config)
+    }
+
+    pub fn ui_config(&self) -> Result<Response<UiConfig>, Box<dyn Error>> {
+        self.get("config/ui", &[])
+    }
+
+    pub fn update_ui_config(&self, config: &UiConfig) -> Result<Response<UiConfig>, Box<dyn Error>> {
+        self.update_config("ui", config.id, config)
+    }
+
+    pub fn indexer_config(&self) -> Result<Response<IndexerConfig>, Box<dyn Error>> {
+        self.get("config/indexer", &[])
+    }
+
+    pub fn update_indexer_config(&self, config: &IndexerConfig) -> Result<Response<IndexerConfig>, Box<dyn Error>> {
+        self.update_config("indexer", config.id, config)
+    }
+
+    // a config section (eg: `mediamanagement`) exactly as the server sends it, including the keys
+    // that are unset. The typed config getters leave those out.
+    pub fn raw_config(&self, section: &str) -> Result<Response<serde_json::Value>, Box<dyn Error>> {
+        self.get(&format!("config/{}", section), &[])
+    }
+
+    pub fn manual_import_candidates(&self, folder: &str, movie_id: Option<u32>) -> Result<Response<Vec<ManualImportItem>>, Box<dyn Error>> {
+        let movie_id = movie_id.map(|id| id.to_string());
+        let mut params = vec![("folder", folder), ("filterExistingFiles", "true")];
+
+        if let Some(movie_id) = &movie_id {
+            params.push(("movieId", movie_id));
+        }
+
+        self.get("manualimport", &params)
+    }
+
+    pub fn manual_import(&self, payload: &ManualImportPayload) -> Result<Response<CommandResponse>, Box<dyn Error>> {
+        self.command(payload)
+    }
+
+    // preview how the movie's files would be renamed under the current naming settings
+    pub fn rename_preview(&self, movie_id: u32) -> Result<Response<Vec<RenamePreview>>, Box<dyn Error>> {
+        let movie_id = movie_id.to_string();
+
+        self.get("rename", &[("movieId", &movie_id)])
+    }
+
+    pub fn rename_files(&self, movie_id: u32, file_ids: &[u32]) -> Result<Response<CommandResponse>, Box<dyn Error>> {
+        let payload = RenameFilesPayload::new(movie_id, file_ids.to_vec());
+
+        self.command(&payload)
+    }
+
+    pub fn backups(&self) -> Result<Response<Vec<Backup>>, Box<dyn Error>> {
+        self.get("system/backup", &[])
+    }
+
+    pub fn create_backup(&self) -> Result<Response<CommandResponse>, Box<dyn Error>> {
+        self.command(&json!({ "name": "Backup" }))
+    }
+
+    pub fn delete_backup(&self, id: u32) -> Result<Response<()>, Box<dyn Error>> {
+        self.send(reqwest::Method::DELETE, &format!("system/backup/{}", id), &[], None::<&()>)
+    }
+
+    // stream the backup's zip into `writer`. The response data is the number of bytes written.
+    pub fn download_backup<W: Write>(&self, backup: &Backup, writer: &mut W) -> Result<Response<u64>, Box<dyn Error>> {
+        self.download(&backup.path, writer)
+    }
+
+    // restore one of the backups already on the server. Radarr restarts itself afterwards.
+    pub fn restore_backup(&self, id: u32) -> Result<Response<serde_json::Value>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, &format!("system/backup/restore/{}", id), &[], None::<&()>)
+    }
+
+    // upload a backup zip and restore it. Radarr restarts itself afterwards.
+    pub fn restore_backup_upload(&self, file_path: &Path) -> Result<Response<serde_json::Value>, Box<dyn Error>> {
+        let query_string = self.query_string(&[]);
+        let url = self.api_url_for("system/backup/restore/upload", &query_string);
+        let form = reqwest::multipart::Form::new().file("restore", file_path)?;
+
+        let mut resp = reqwest::Client::new()
+            .post(&url)
+            .multipart(form)
+            .send()?;
+        let body = resp.text()?;
+
+        if !resp.status().is_success() {
+            return Err(Box::new(error::ApiError::new(resp.status().as_u16(), &body)));
+        }
+
+        let body = if body.trim().is_empty() { "null" } else { &body };
+        let data = serde_json::from_str(body)?;
+
+        Ok(Response::new(resp, data))
+    }
+
+    // a page of log entries, newest first. `level` filters to that level and above (eg: `warn`).
+    pub fn logs(&self, page: u32, page_size: u32, level: Option<&str>) -> Result<Response<Page<LogEntry>>, Box<dyn Error>> {
+        let page = page.to_string();
+        let page_size = page_size.to_string();
+        let mut params = vec![
+            ("page", page.as_str()),
+            ("pageSize", page_size.as_str()),
+            ("sortKey", "time"),
+            ("sortDirection", "descending"),
+        ];
+
+        if let Some(level) = level {
+            params.push(("level", level));
+        }
+
+        self.get("log", &params)
+    }
+
+    pub fn log_files(&self) -> Result<Response<Vec<LogFile>>, Box<dyn Error>> {
+        self.get("log/file", &[])
+    }
+
+    pub fn update_log_files(&self) -> Result<Response<Vec<LogFile>>, Box<dyn Error>> {
+        self.get("log/file/update", &[])
+    }
+
+    // stream the contents of the log file into `writer`. The response data is the number of bytes written.
+    pub fn download_log_file<W: Write>(&self, log_file: &LogFile, writer: &mut W) -> Result<Response<u64>, Box<dyn Error>> {
+        self.download(&log_file.contents_url, writer)
+    }
+
+    // queue a command. The payload must contain the command's `name` along with any arguments.
+    pub fn command<P: Serialize + ?Sized>(&self, payload: &P) -> Result<Response<CommandResponse>, Box<dyn Error>> {
+        self.send(reqwest::Method::POST, "command", &[], Some(payload))
+    }
+
+    pub fn get_command(&self, id: u32) -> Result<Response<CommandResponse>, Box<dyn Error>> {
+        self.get(&format!("command/{}", id), &[])
     }
 
     pub fn url_for(&self, uri: &str, query_string: &str) -> String {
@@ -173,4 +644,81 @@ impl Client {
 
         self.url_for(&uri, query_string)
     }
+
+    // stream a non-api path (relative to the url base) into `writer`
+    fn download<W: Write>(&self, path: &str, writer: &mut W) -> Result<Response<u64>, Box<dyn Error>> {
+        let query_string = self.query_string(&[]);
+        let url = self.url_for(path.trim_start_matches('/'), &query_string);
+
+        let mut resp = reqwest::get(&url)?;
+
+        if !resp.status().is_success() {
+            let body = resp.text()?;
+            return Err(Box::new(error::ApiError::new(resp.status().as_u16(), &body)));
+        }
+
+        let bytes = resp.copy_to(writer)?;
+
+        Ok(Response::new(resp, bytes))
+    }
+
+    // config sections are singletons, but still live at `config/<section>/<id>`
+    fn update_config<T>(&self, section: &str, id: Option<u32>, config: &T) -> Result<Response<T>, Box<dyn Error>>
+        where T: DeserializeOwned + Serialize + Debug
+    {
+        let uri = format!("config/{}/{}", section, id.unwrap_or(1));
+
+        self.send(reqwest::Method::PUT, &uri, &[], Some(config))
+    }
+
+    fn query_string(&self, params: &[(&str, &str)]) -> String {
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+
+        for (key, value) in params {
+            serializer.append_pair(key, value);
+        }
+
+        serializer
+            .append_pair("apikey", &self.config.api_token)
+            .finish()
+    }
+
+    // GET the given api uri and deserialize the json body.
+    fn get<T>(&self, uri: &str, params: &[(&str, &str)]) -> Result<Response<T>, Box<dyn Error>>
+        where T: DeserializeOwned + Serialize + Debug
+    {
+        self.send(reqwest::Method::GET, uri, params, None::<&()>)
+    }
+
+    // send a request with an optional json payload. Non-2xx responses are turned into an
+    // `error::ApiError` carrying the body Radarr sent back. Empty bodies deserialize as `null`
+    // so callers that don't care about the response can use `()`.
+    fn send<P, T>(&self, method: reqwest::Method, uri: &str, params: &[(&str, &str)], payload: Option<&P>) -> Result<Response<T>, Box<dyn Error>>
+        where P: Serialize + ?Sized,
+              T: DeserializeOwned + Serialize + Debug
+    {
+        let query_string = self.query_string(params);
+        let url = self.api_url_for(uri, &query_string);
+        let client = reqwest::Client::new();
+
+        let mut request = client.request(method, &url);
+
+        if let Some(payload) = payload {
+            request = request
+                .body(serde_json::to_string(payload)?)
+                .header("content-type", "application/json");
+        }
+
+        let mut resp = request.send()?;
+        let body = resp.text()?;
+
+        if !resp.status().is_success() {
+            return Err(Box::new(error::ApiError::new(resp.status().as_u16(), &body)));
+        }
+
+        let body = if body.trim().is_empty() { "null" } else { &body };
+        let data: T = serde_json::from_str(body)?;
+
+        Ok(Response::new(resp, data))
+    }
 }
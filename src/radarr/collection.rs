@@ -0,0 +1,122 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+use super::enums::MinimumAvailability;
+use super::image::Image;
+
+// id: 4
+// title: "The Matrix Collection"
+// tmdbId: 2344
+// monitored: false
+// searchOnAdd: true
+// qualityProfileId: 1
+// rootFolderPath: "/storage/Movies/"
+// minimumAvailability: "released"
+// movies: [{ tmdbId: 603, title: "The Matrix", year: 1999, isExisting: true, isExcluded: false, ... }]
+//
+// `monitored` makes Radarr add new members of the collection by itself, using the quality
+// profile, root folder and minimum availability set on the collection.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Collection {
+    pub id: u32,
+    pub title: String,
+
+    #[serde(rename = "tmdbId")]
+    pub tmdb_id: u32,
+
+    pub monitored: bool,
+
+    #[serde(rename = "searchOnAdd", default)]
+    pub search_on_add: bool,
+
+    #[serde(rename = "qualityProfileId", default)]
+    pub quality_profile_id: u32,
+
+    #[serde(rename = "rootFolderPath")]
+    pub root_folder_path: Option<String>,
+
+    #[serde(rename = "minimumAvailability")]
+    pub minimum_availability: Option<MinimumAvailability>,
+
+    #[serde(default)]
+    pub movies: Vec<CollectionMovie>,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CollectionMovie {
+    pub title: String,
+
+    #[serde(rename = "tmdbId")]
+    pub tmdb_id: u32,
+
+    #[serde(rename = "imdbId")]
+    pub imdb_id: Option<String>,
+
+    #[serde(default)]
+    pub year: u32,
+
+    #[serde(default)]
+    pub images: Vec<Image>,
+
+    #[serde(rename = "isExisting", default)]
+    pub is_existing: bool,
+
+    #[serde(rename = "isExcluded", default)]
+    pub is_excluded: bool,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl Collection {
+    // members of the collection that aren't in the library. Movies on the import exclusion list are
+    // only included when asked for.
+    pub fn missing_movies(&self, include_excluded: bool) -> Vec<&CollectionMovie> {
+        self.movies.iter()
+            .filter(|movie| !movie.is_existing && (include_excluded || !movie.is_excluded))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collection() -> Collection {
+        serde_json::from_value(serde_json::json!({
+            "id": 4,
+            "title": "The Matrix Collection",
+            "tmdbId": 2344,
+            "monitored": false,
+            "overview": "kept in other",
+            "movies": [
+                { "title": "The Matrix", "tmdbId": 603, "year": 1999, "isExisting": true, "isExcluded": false },
+                { "title": "The Matrix Reloaded", "tmdbId": 604, "year": 2003, "isExisting": false, "isExcluded": false },
+                { "title": "The Matrix Revolutions", "tmdbId": 605, "year": 2003, "isExisting": false, "isExcluded": true },
+            ],
+        })).unwrap()
+    }
+
+    #[test]
+    fn missing_movies_skips_existing_and_excluded() {
+        let collection = collection();
+
+        let missing: Vec<u32> = collection.missing_movies(false).iter().map(|movie| movie.tmdb_id).collect();
+        assert_eq!(missing, vec![604]);
+
+        let missing: Vec<u32> = collection.missing_movies(true).iter().map(|movie| movie.tmdb_id).collect();
+        assert_eq!(missing, vec![604, 605]);
+    }
+
+    #[test]
+    fn unknown_fields_survive_a_round_trip() {
+        let value = serde_json::to_value(collection()).unwrap();
+
+        assert_eq!(value["overview"], "kept in other");
+        assert_eq!(value["searchOnAdd"], false);
+    }
+}
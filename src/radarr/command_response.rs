@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+// name: "RenameFiles"
+// commandName: "Rename Files"
+// status: "queued"
+// queued: "2021-04-01T20:31:12Z"
+// trigger: "manual"
+// sendUpdatesToClient: true
+// updateScheduledTask: true
+// id: 1234
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommandResponse {
+    pub name: String,
+
+    #[serde(rename = "commandName")]
+    pub command_name: Option<String>,
+
+    pub status: String,
+
+    #[serde(default, with = "super::datetime::option")]
+    pub queued: Option<DateTime<Utc>>,
+
+    #[serde(default, with = "super::datetime::option")]
+    pub started: Option<DateTime<Utc>>,
+
+    #[serde(default, with = "super::datetime::option")]
+    pub ended: Option<DateTime<Utc>>,
+
+    pub trigger: Option<String>,
+    pub message: Option<String>,
+    pub id: u32,
+}
+
+impl CommandResponse {
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status.as_str(), "completed" | "failed" | "aborted" | "cancelled" | "orphaned")
+    }
+}
@@ -27,10 +27,7 @@ impl Config {
     }
 
     pub fn new_from_env_with_defaults() -> Config {
-        let api_token: Option<String> = match env::var(ENV_RADARR_API_TOKEN) {
-            Ok(api_token) => Some(String::from(api_token)),
-            Err(_) => None,
-        };
+        let api_token = env::var(ENV_RADARR_API_TOKEN).ok();
 
         let hostname = env::var(ENV_RADARR_API_HOSTNAME)
             .unwrap_or(String::from(DEFAULT_HOSTNAME));
@@ -84,7 +81,7 @@ impl Config {
             fields.push(String::from("protocol"));
         }
 
-        if fields.len() == 0 {
+        if fields.is_empty() {
             None
         } else {
             Some(error::ConfigNotMaterializeable::with_fields(fields))
@@ -0,0 +1,54 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// personName: "Lana Wachowski"
+// personTmdbId: 9339
+// creditTmdbId: "52fe425bc3a36847f80181c1"
+// department: "Directing"
+// job: "Director"
+// character: null
+// order: 0
+// type: "crew"
+// id: 12
+//
+// cast credits have a `character` and an `order`, crew credits a `department` and a `job`.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Credit {
+    pub id: u32,
+
+    #[serde(rename = "personName")]
+    pub person_name: String,
+
+    #[serde(rename = "personTmdbId")]
+    pub person_tmdb_id: u32,
+
+    #[serde(rename = "creditTmdbId")]
+    pub credit_tmdb_id: Option<String>,
+
+    pub department: Option<String>,
+    pub job: Option<String>,
+    pub character: Option<String>,
+
+    #[serde(default)]
+    pub order: u32,
+
+    #[serde(rename = "type")]
+    pub credit_type: String,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl Credit {
+    pub fn is_cast(&self) -> bool {
+        self.credit_type.eq_ignore_ascii_case("cast")
+    }
+
+    // what the person did: the character they played for cast, their job for crew
+    pub fn role(&self) -> &str {
+        let role = if self.is_cast() { &self.character } else { &self.job };
+
+        role.as_deref().unwrap_or("")
+    }
+}
@@ -0,0 +1,326 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Map, Value};
+
+// The api sends specification fields as a list of form fields:
+//
+// {
+//   "id": 3,
+//   "name": "x265",
+//   "includeCustomFormatWhenRenaming": false,
+//   "specifications": [
+//     {
+//       "name": "x265",
+//       "implementation": "ReleaseTitleSpecification",
+//       "implementationName": "Release Title",
+//       "negate": false,
+//       "required": true,
+//       "fields": [{ "order": 0, "name": "value", "label": "Regular Expression", "value": "[xh]\\.?265" }]
+//     }
+//   ]
+// }
+//
+// while the web UI's export button drops the ids and flattens the fields into an object:
+//
+//       "fields": { "value": "[xh]\\.?265" }
+//
+// `CustomFormat` reads either shape, serializes to the api shape, and `export()` produces the UI shape.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CustomFormat {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    pub name: String,
+
+    #[serde(rename = "includeCustomFormatWhenRenaming", default)]
+    pub include_custom_format_when_renaming: bool,
+
+    pub specifications: Vec<Specification>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "RawSpecification", into = "RawSpecification")]
+pub struct Specification {
+    pub name: String,
+    pub negate: bool,
+    pub required: bool,
+    pub kind: SpecificationKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpecificationKind {
+    // a regular expression matched against the release title
+    ReleaseTitle(String),
+    ReleaseGroup(String),
+    Edition(String),
+
+    // the remaining ones hold the id of whatever Radarr is matching on (a language, source,
+    // resolution, etc.)
+    Language(i64),
+    IndexerFlag(i64),
+    Source(i64),
+    Resolution(i64),
+    QualityModifier(i64),
+
+    // size limits in GB
+    Size { min: f64, max: f64 },
+
+    // anything this library doesn't know about, kept as-is so it round-trips
+    Other { implementation: String, fields: Map<String, Value> },
+}
+
+impl SpecificationKind {
+    pub fn implementation(&self) -> &str {
+        match self {
+            SpecificationKind::ReleaseTitle(_) => "ReleaseTitleSpecification",
+            SpecificationKind::ReleaseGroup(_) => "ReleaseGroupSpecification",
+            SpecificationKind::Edition(_) => "EditionSpecification",
+            SpecificationKind::Language(_) => "LanguageSpecification",
+            SpecificationKind::IndexerFlag(_) => "IndexerFlagSpecification",
+            SpecificationKind::Source(_) => "SourceSpecification",
+            SpecificationKind::Resolution(_) => "ResolutionSpecification",
+            SpecificationKind::QualityModifier(_) => "QualityModifierSpecification",
+            SpecificationKind::Size { .. } => "SizeSpecification",
+            SpecificationKind::Other { implementation, .. } => implementation,
+        }
+    }
+
+    fn from_fields(implementation: &str, fields: Map<String, Value>) -> SpecificationKind {
+        let string = |key: &str| fields.get(key).and_then(Value::as_str).map(String::from);
+        let integer = |key: &str| fields.get(key).and_then(Value::as_i64);
+        let float = |key: &str| fields.get(key).and_then(Value::as_f64);
+
+        // the typed kinds only hold these fields, so anything else (eg: a newer version's
+        // `exceptLanguage`) has to stay in `Other` to survive the round-trip
+        let known_fields: &[&str] = match implementation {
+            "SizeSpecification" => &["min", "max"],
+            _ => &["value"],
+        };
+        let has_extra_fields = fields.keys().any(|key| !known_fields.contains(&key.as_str()));
+
+        let kind = match implementation {
+            _ if has_extra_fields => None,
+            "ReleaseTitleSpecification" => string("value").map(SpecificationKind::ReleaseTitle),
+            "ReleaseGroupSpecification" => string("value").map(SpecificationKind::ReleaseGroup),
+            "EditionSpecification" => string("value").map(SpecificationKind::Edition),
+            "LanguageSpecification" => integer("value").map(SpecificationKind::Language),
+            "IndexerFlagSpecification" => integer("value").map(SpecificationKind::IndexerFlag),
+            "SourceSpecification" => integer("value").map(SpecificationKind::Source),
+            "ResolutionSpecification" => integer("value").map(SpecificationKind::Resolution),
+            "QualityModifierSpecification" => integer("value").map(SpecificationKind::QualityModifier),
+            "SizeSpecification" => match (float("min"), float("max")) {
+                (Some(min), Some(max)) => Some(SpecificationKind::Size { min, max }),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        kind.unwrap_or_else(|| SpecificationKind::Other {
+            implementation: String::from(implementation),
+            fields,
+        })
+    }
+
+    fn fields(&self) -> Map<String, Value> {
+        let value = match self {
+            SpecificationKind::ReleaseTitle(value)
+                | SpecificationKind::ReleaseGroup(value)
+                | SpecificationKind::Edition(value) => json!({ "value": value }),
+            SpecificationKind::Language(value)
+                | SpecificationKind::IndexerFlag(value)
+                | SpecificationKind::Source(value)
+                | SpecificationKind::Resolution(value)
+                | SpecificationKind::QualityModifier(value) => json!({ "value": value }),
+            SpecificationKind::Size { min, max } => json!({ "min": min, "max": max }),
+            SpecificationKind::Other { fields, .. } => return fields.clone(),
+        };
+
+        match value {
+            Value::Object(fields) => fields,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl CustomFormat {
+    // the same json the web UI's export button produces
+    pub fn export(&self) -> Value {
+        let specifications: Vec<Value> = self.specifications.iter()
+            .map(|specification| json!({
+                "name": specification.name,
+                "implementation": specification.kind.implementation(),
+                "negate": specification.negate,
+                "required": specification.required,
+                "fields": specification.kind.fields(),
+            }))
+            .collect();
+
+        json!({
+            "name": self.name,
+            "includeCustomFormatWhenRenaming": self.include_custom_format_when_renaming,
+            "specifications": specifications,
+        })
+    }
+}
+
+// The wire format of a specification. `fields` is either shape described above.
+#[derive(Serialize, Deserialize)]
+struct RawSpecification {
+    name: String,
+    implementation: String,
+
+    #[serde(default)]
+    negate: bool,
+
+    #[serde(default)]
+    required: bool,
+
+    fields: RawFields,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawFields {
+    List(Vec<RawField>),
+    Map(Map<String, Value>),
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawField {
+    name: String,
+
+    #[serde(default)]
+    value: Value,
+}
+
+impl From<RawSpecification> for Specification {
+    fn from(raw: RawSpecification) -> Specification {
+        let fields = match raw.fields {
+            RawFields::List(fields) => fields.into_iter().map(|field| (field.name, field.value)).collect(),
+            RawFields::Map(fields) => fields,
+        };
+
+        Specification {
+            kind: SpecificationKind::from_fields(&raw.implementation, fields),
+            name: raw.name,
+            negate: raw.negate,
+            required: raw.required,
+        }
+    }
+}
+
+impl From<Specification> for RawSpecification {
+    fn from(specification: Specification) -> RawSpecification {
+        let fields = specification.kind.fields().into_iter()
+            .map(|(name, value)| RawField { name, value })
+            .collect();
+
+        RawSpecification {
+            implementation: specification.kind.implementation().to_string(),
+            name: specification.name,
+            negate: specification.negate,
+            required: specification.required,
+            fields: RawFields::List(fields),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_json() -> Value {
+        json!({
+            "id": 3,
+            "name": "x265",
+            "includeCustomFormatWhenRenaming": false,
+            "specifications": [
+                {
+                    "name": "x265",
+                    "implementation": "ReleaseTitleSpecification",
+                    "implementationName": "Release Title",
+                    "negate": false,
+                    "required": true,
+                    "fields": [{ "order": 0, "name": "value", "label": "Regular Expression", "value": "[xh]\\.?265" }],
+                },
+                {
+                    "name": "Not Bluray",
+                    "implementation": "SourceSpecification",
+                    "negate": true,
+                    "required": false,
+                    "fields": [{ "name": "value", "value": 9 }],
+                },
+                {
+                    "name": "Someday",
+                    "implementation": "SomeFutureSpecification",
+                    "negate": false,
+                    "required": false,
+                    "fields": [{ "name": "thing", "value": "stuff" }],
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn reads_api_shape_into_typed_specifications() {
+        let format: CustomFormat = serde_json::from_value(api_json()).unwrap();
+
+        assert_eq!(format.id, Some(3));
+        assert_eq!(format.specifications[0].kind, SpecificationKind::ReleaseTitle(String::from("[xh]\\.?265")));
+        assert_eq!(format.specifications[1].kind, SpecificationKind::Source(9));
+        assert!(format.specifications[1].negate);
+        assert_eq!(format.specifications[2].kind.implementation(), "SomeFutureSpecification");
+    }
+
+    #[test]
+    fn export_matches_web_ui_shape() {
+        let format: CustomFormat = serde_json::from_value(api_json()).unwrap();
+        let export = format.export();
+
+        assert!(export.get("id").is_none());
+        assert_eq!(export["specifications"][0], json!({
+            "name": "x265",
+            "implementation": "ReleaseTitleSpecification",
+            "negate": false,
+            "required": true,
+            "fields": { "value": "[xh]\\.?265" },
+        }));
+    }
+
+    #[test]
+    fn exported_json_imports_back() {
+        let format: CustomFormat = serde_json::from_value(api_json()).unwrap();
+        let imported: CustomFormat = serde_json::from_value(format.export()).unwrap();
+
+        assert_eq!(imported.id, None);
+        assert_eq!(imported.specifications, format.specifications);
+    }
+
+    #[test]
+    fn extra_fields_survive_the_round_trip() {
+        let specification = json!({
+            "name": "Not Original Language",
+            "implementation": "LanguageSpecification",
+            "negate": false,
+            "required": false,
+            // fields come back sorted by name
+            "fields": [{ "name": "exceptLanguage", "value": true }, { "name": "value", "value": -2 }],
+        });
+
+        let parsed: Specification = serde_json::from_value(specification.clone()).unwrap();
+        assert_eq!(parsed.kind.implementation(), "LanguageSpecification");
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), specification);
+
+        let format = CustomFormat { id: None, name: String::from("Language"), include_custom_format_when_renaming: false, specifications: vec![parsed] };
+        let imported: CustomFormat = serde_json::from_value(format.export()).unwrap();
+        assert_eq!(imported.specifications, format.specifications);
+    }
+
+    #[test]
+    fn serializes_fields_as_api_list() {
+        let format: CustomFormat = serde_json::from_value(api_json()).unwrap();
+        let json = serde_json::to_value(&format).unwrap();
+
+        assert_eq!(json["specifications"][1]["fields"], json!([{ "name": "value", "value": 9 }]));
+    }
+}
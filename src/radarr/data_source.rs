@@ -2,7 +2,6 @@ use clap::ArgMatches;
 use std::error::Error;
 
 use std::fs::File;
-use std::io::prelude::*;
 
 use std::io::{self, Read};
 
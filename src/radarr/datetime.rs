@@ -0,0 +1,107 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+// Timestamps as Radarr sends them. They're normally RFC 3339 in UTC ("2017-01-23T22:05:32.365337Z"),
+// but anything without an offset is taken to be UTC as well, and a bare date is midnight.
+pub fn parse_datetime(value: &str) -> Result<DateTime<Utc>, String> {
+    let value = value.trim();
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    if let Ok(time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Ok(time.and_utc());
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+        .map_err(|_| format!("Failed to parse date: `{}`", value))
+}
+
+// How long ago (or until) `time` is, roughly: "just now", "3 days ago", "in 2 weeks"
+pub fn relative_time(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = now.signed_duration_since(time).num_seconds();
+    let elapsed = seconds.unsigned_abs();
+
+    let (count, unit) = match elapsed {
+        0..=59 => return String::from("just now"),
+        60..=3_599 => (elapsed / 60, "minute"),
+        3_600..=86_399 => (elapsed / 3_600, "hour"),
+        86_400..=1_209_599 => (elapsed / 86_400, "day"),
+        1_209_600..=5_183_999 => (elapsed / 604_800, "week"),
+        5_184_000..=31_535_999 => (elapsed / 2_592_000, "month"),
+        _ => (elapsed / 31_536_000, "year"),
+    };
+
+    let plural = if count == 1 { "" } else { "s" };
+
+    if seconds < 0 {
+        format!("in {} {}{}", count, unit, plural)
+    } else {
+        format!("{} {}{} ago", count, unit, plural)
+    }
+}
+
+// For `#[serde(with = "...")]` on required timestamps
+pub fn serialize<S: Serializer>(time: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&time.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    parse_datetime(&value).map_err(serde::de::Error::custom)
+}
+
+// For optional timestamps. These need `#[serde(default)]` too, so a missing field is `None`.
+pub mod option {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(time: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error> {
+        match time {
+            Some(time) => super::serialize(time, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    // an empty string is as good as no date
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) if !value.trim().is_empty() => super::parse_datetime(&value).map(Some).map_err(serde::de::Error::custom),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn parses_radarr_timestamps() {
+        let expected = Utc.with_ymd_and_hms(2017, 1, 23, 22, 5, 32).unwrap();
+
+        assert_eq!(parse_datetime("2017-01-23T22:05:32Z").unwrap(), expected);
+        assert_eq!(parse_datetime("2017-01-23T23:05:32+01:00").unwrap(), expected);
+        assert_eq!(parse_datetime("2017-01-23T22:05:32").unwrap(), expected);
+        assert_eq!(parse_datetime("2017-01-23T22:05:32.365337Z").unwrap().timestamp(), expected.timestamp());
+        assert_eq!(parse_datetime("2017-01-23").unwrap(), Utc.with_ymd_and_hms(2017, 1, 23, 0, 0, 0).unwrap());
+        assert!(parse_datetime("last tuesday").is_err());
+    }
+
+    #[test]
+    fn describes_relative_times() {
+        let now = Utc.with_ymd_and_hms(2021, 6, 27, 12, 0, 0).unwrap();
+
+        assert_eq!(relative_time(now - Duration::seconds(30), now), "just now");
+        assert_eq!(relative_time(now - Duration::minutes(1), now), "1 minute ago");
+        assert_eq!(relative_time(now - Duration::hours(5), now), "5 hours ago");
+        assert_eq!(relative_time(now - Duration::days(3), now), "3 days ago");
+        assert_eq!(relative_time(now - Duration::days(20), now), "2 weeks ago");
+        assert_eq!(relative_time(now - Duration::days(100), now), "3 months ago");
+        assert_eq!(relative_time(now - Duration::days(800), now), "2 years ago");
+        assert_eq!(relative_time(now + Duration::days(2), now), "in 2 days");
+    }
+}
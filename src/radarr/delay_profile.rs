@@ -0,0 +1,66 @@
+use serde::{Serialize, Deserialize};
+
+// enableUsenet: true
+// enableTorrent: true
+// preferredProtocol: "usenet"
+// usenetDelay: 0
+// torrentDelay: 120
+// bypassIfHighestQuality: true
+// order: 1
+// tags: [2]
+// id: 3
+//
+// delays are in minutes. The profile with no tags is the default and always sorts last.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DelayProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    #[serde(rename = "enableUsenet")]
+    pub enable_usenet: bool,
+
+    #[serde(rename = "enableTorrent")]
+    pub enable_torrent: bool,
+
+    #[serde(rename = "preferredProtocol")]
+    pub preferred_protocol: String,
+
+    #[serde(rename = "usenetDelay")]
+    pub usenet_delay: u32,
+
+    #[serde(rename = "torrentDelay")]
+    pub torrent_delay: u32,
+
+    #[serde(rename = "bypassIfHighestQuality", default)]
+    pub bypass_if_highest_quality: bool,
+
+    #[serde(default)]
+    pub order: u32,
+
+    #[serde(default)]
+    pub tags: Vec<u32>,
+}
+
+impl Default for DelayProfile {
+    fn default() -> DelayProfile {
+        DelayProfile {
+            id: None,
+            enable_usenet: true,
+            enable_torrent: true,
+            preferred_protocol: String::from("usenet"),
+            usenet_delay: 0,
+            torrent_delay: 0,
+            bypass_if_highest_quality: false,
+            order: 0,
+            tags: vec![],
+        }
+    }
+}
+
+impl DelayProfile {
+    // the built-in profile for movies no other profile's tags match. Radarr won't let it have tags.
+    pub fn is_default(&self) -> bool {
+        self.id == Some(1)
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Serialize, Deserialize};
+
+// path: "/storage"
+// label: "storage"
+// freeSpace: 1245057187328
+// totalSpace: 46891755717632
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DiskSpace {
+    pub path: String,
+    pub label: Option<String>,
+
+    #[serde(rename = "freeSpace")]
+    pub free_space: u64,
+
+    #[serde(rename = "totalSpace")]
+    pub total_space: u64,
+}
+
+impl DiskSpace {
+    pub fn used_space(&self) -> u64 {
+        self.total_space.saturating_sub(self.free_space)
+    }
+
+    // percentage of the disk that's in use. Empty (or unreported) disks are 0% used.
+    pub fn used_percent(&self) -> f64 {
+        if self.total_space == 0 {
+            return 0.0;
+        }
+
+        self.used_space() as f64 / self.total_space as f64 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk(free_space: u64, total_space: u64) -> DiskSpace {
+        DiskSpace {
+            path: String::from("/storage"),
+            label: None,
+            free_space,
+            total_space,
+        }
+    }
+
+    #[test]
+    fn used_percent_is_share_of_total() {
+        let disk = disk(250, 1000);
+
+        assert_eq!(disk.used_space(), 750);
+        assert!((disk.used_percent() - 75.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn used_percent_handles_zero_sized_disks() {
+        let disk = disk(0, 0);
+
+        assert_eq!(disk.used_space(), 0);
+        assert!(disk.used_percent().abs() < f64::EPSILON);
+    }
+}
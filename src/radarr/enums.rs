@@ -0,0 +1,161 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Deserialize};
+
+// Defines an enum for a string field of the API. Every known wire value gets its own variant and
+// anything else ends up in `Unknown` unchanged, so a newer Radarr never breaks deserialization
+// and the value still round-trips.
+macro_rules! string_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)+ }) => {
+        $(#[$meta])*
+        #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $($variant,)+
+            Unknown(String),
+        }
+
+        impl $name {
+            // every wire value this library knows about
+            pub const VALUES: &'static [&'static str] = &[$($value),+];
+
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Unknown(value) => value,
+                }
+            }
+
+            pub fn is_known(&self) -> bool {
+                !matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> $name {
+                match value.as_str() {
+                    $($value => $name::$variant,)+
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> String {
+                match value {
+                    $name::Unknown(value) => value,
+                    known => known.as_str().to_string(),
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> Result<$name, Infallible> {
+                Ok($name::from(value.to_string()))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.pad(self.as_str())
+            }
+        }
+    };
+}
+
+string_enum! {
+    // Release status of a movie
+    MovieStatus {
+        Tba => "tba",
+        Announced => "announced",
+        InCinemas => "inCinemas",
+        Released => "released",
+        Deleted => "deleted",
+    }
+}
+
+string_enum! {
+    // When Radarr considers a movie available and starts grabbing releases for it. `preDB` is
+    // only sent by older versions.
+    MinimumAvailability {
+        Tba => "tba",
+        Announced => "announced",
+        InCinemas => "inCinemas",
+        Released => "released",
+        PreDb => "preDB",
+    }
+}
+
+impl MinimumAvailability {
+    // the values Radarr accepts when adding or editing a movie
+    pub const SELECTABLE: &'static [&'static str] = &["announced", "inCinemas", "released"];
+
+    pub fn is_selectable(&self) -> bool {
+        MinimumAvailability::SELECTABLE.contains(&self.as_str())
+    }
+}
+
+string_enum! {
+    // Kind of artwork an `Image` points to
+    CoverType {
+        Poster => "poster",
+        Banner => "banner",
+        Fanart => "fanart",
+        Screenshot => "screenshot",
+        Headshot => "headshot",
+        Clearlogo => "clearlogo",
+    }
+}
+
+string_enum! {
+    // Severity of a health check
+    HealthType {
+        Ok => "ok",
+        Notice => "notice",
+        Warning => "warning",
+        Error => "error",
+    }
+}
+
+string_enum! {
+    // What Radarr monitors once a movie is added
+    MonitorMode {
+        MovieOnly => "movieOnly",
+        MovieAndCollection => "movieAndCollection",
+        None => "none",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn known_values_map_to_variants() {
+        let status: MovieStatus = serde_json::from_value(json!("inCinemas")).unwrap();
+        assert_eq!(status, MovieStatus::InCinemas);
+        assert_eq!(serde_json::to_value(&status).unwrap(), json!("inCinemas"));
+        assert_eq!("preDB".parse::<MinimumAvailability>().unwrap(), MinimumAvailability::PreDb);
+    }
+
+    #[test]
+    fn unknown_values_round_trip() {
+        let cover: CoverType = serde_json::from_value(json!("disc")).unwrap();
+        assert_eq!(cover, CoverType::Unknown(String::from("disc")));
+        assert!(!cover.is_known());
+        assert_eq!(serde_json::to_value(&cover).unwrap(), json!("disc"));
+        assert_eq!(cover.to_string(), "disc");
+    }
+
+    #[test]
+    fn only_some_availabilities_are_selectable() {
+        assert!(MinimumAvailability::Released.is_selectable());
+        assert!(!MinimumAvailability::PreDb.is_selectable());
+        assert!(!MinimumAvailability::Unknown(String::from("soon")).is_selectable());
+    }
+}
@@ -1,17 +1,92 @@
 use std::fmt;
 use std::error;
 
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
 #[derive(Debug, Clone)]
 pub struct UnableToAddMovie {
     msg: String,
+    status: Option<u16>,
+    failures: Vec<ValidationFailure>,
+}
+
+// one of the reasons Radarr gives for rejecting a request, eg:
+// { "propertyName": "Path", "errorMessage": "Path is already configured for an existing movie", ... }
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ValidationFailure {
+    #[serde(rename = "propertyName", default)]
+    pub property_name: String,
+
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
+
+    #[serde(rename = "attemptedValue", default)]
+    pub attempted_value: Value,
 }
 
 impl UnableToAddMovie {
     pub fn with_msg(msg: &str) -> UnableToAddMovie {
         UnableToAddMovie {
             msg: String::from(msg),
+            status: None,
+            failures: vec![],
+        }
+    }
+
+    // for payloads that are rejected before they're sent
+    pub fn with_failures(failures: Vec<ValidationFailure>) -> UnableToAddMovie {
+        UnableToAddMovie {
+            msg: ValidationFailure::summarize(&failures),
+            status: None,
+            failures,
+        }
+    }
+
+    // build the error from a rejected request, picking out Radarr's validation failures if the
+    // body has them
+    pub fn from_response(status: u16, body: &str) -> UnableToAddMovie {
+        let failures: Vec<ValidationFailure> = serde_json::from_str(body).unwrap_or_default();
+
+        let msg = if failures.is_empty() {
+            serde_json::from_str::<Value>(body).ok()
+                .and_then(|value| value["message"].as_str().map(String::from))
+                .unwrap_or_else(|| format!("Radarr returned {}: {}", status, body))
+        } else {
+            ValidationFailure::summarize(&failures)
+        };
+
+        UnableToAddMovie {
+            msg,
+            status: Some(status),
+            failures,
         }
     }
+
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    pub fn failures(&self) -> &[ValidationFailure] {
+        &self.failures
+    }
+}
+
+impl ValidationFailure {
+    pub fn new(property_name: &str, error_message: &str, attempted_value: Value) -> ValidationFailure {
+        ValidationFailure {
+            property_name: String::from(property_name),
+            error_message: String::from(error_message),
+            attempted_value,
+        }
+    }
+
+    fn summarize(failures: &[ValidationFailure]) -> String {
+        failures.iter()
+            .map(|failure| format!("{}: {}", failure.property_name, failure.error_message))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
 }
 
 impl fmt::Display for UnableToAddMovie {
@@ -26,7 +101,46 @@ impl error::Error for UnableToAddMovie {
         "unable to add movie"
     }
 
-    fn cause(&self) -> Option<&error::Error> {
+    fn cause(&self) -> Option<&dyn error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MovieExists {
+    pub title: String,
+    pub tmdb_id: u32,
+
+    // optional so callers that only know the tmdb id can still report the movie
+    pub movie_id: Option<u32>,
+}
+
+impl MovieExists {
+    pub fn new(title: &str, tmdb_id: u32, movie_id: Option<u32>) -> MovieExists {
+        MovieExists {
+            title: String::from(title),
+            tmdb_id,
+            movie_id,
+        }
+    }
+}
+
+impl fmt::Display for MovieExists {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.movie_id {
+            Some(movie_id) => write!(f, "{} (tmdb {}) is already in the library with ID {}", self.title, self.tmdb_id, movie_id),
+            None => write!(f, "{} (tmdb {}) is already in the library", self.title, self.tmdb_id),
+        }
+    }
+}
+
+impl error::Error for MovieExists {
+    fn description(&self) -> &str {
+        "movie already exists"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
         // Generic error, underlying cause isn't tracked.
         None
     }
@@ -56,8 +170,98 @@ impl error::Error for ConfigNotMaterializeable {
         "unable to materialize config"
     }
 
-    fn cause(&self) -> Option<&error::Error> {
+    fn cause(&self) -> Option<&dyn error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    pub status: u16,
+    pub body: String,
+}
+
+impl ApiError {
+    pub fn new(status: u16, body: &str) -> ApiError {
+        ApiError {
+            status,
+            body: String::from(body),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Radarr API returned {}: {}", self.status, self.body)
+    }
+}
+
+impl error::Error for ApiError {
+    fn description(&self) -> &str {
+        "radarr api error"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
         // Generic error, underlying cause isn't tracked.
         None
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct NoRootFolder {
+    msg: String,
+}
+
+impl NoRootFolder {
+    pub fn with_msg(msg: &str) -> NoRootFolder {
+        NoRootFolder {
+            msg: String::from(msg),
+        }
+    }
+}
+
+impl fmt::Display for NoRootFolder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unable to pick a root folder: {}", self.msg)
+    }
+}
+
+impl error::Error for NoRootFolder {
+    fn description(&self) -> &str {
+        "unable to pick a root folder"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unable_to_add_movie_collects_validation_failures() {
+        let body = r#"[
+            { "propertyName": "QualityProfileId", "errorMessage": "Must be greater than '0'.", "attemptedValue": 0, "severity": "error" },
+            { "propertyName": "Path", "errorMessage": "Path is already configured for an existing movie" }
+        ]"#;
+
+        let error = UnableToAddMovie::from_response(400, body);
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(error.failures().len(), 2);
+        assert_eq!(error.to_string(), "Unable to add movie: QualityProfileId: Must be greater than '0'.; Path: Path is already configured for an existing movie");
+    }
+
+    #[test]
+    fn unable_to_add_movie_falls_back_to_message_or_body() {
+        let error = UnableToAddMovie::from_response(500, r#"{ "message": "Something broke" }"#);
+        assert_eq!(error.to_string(), "Unable to add movie: Something broke");
+        assert!(error.failures().is_empty());
+
+        let error = UnableToAddMovie::from_response(502, "Bad Gateway");
+        assert_eq!(error.to_string(), "Unable to add movie: Radarr returned 502: Bad Gateway");
+    }
+}
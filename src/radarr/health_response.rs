@@ -1,5 +1,7 @@
 use serde::{Serialize, Deserialize};
 
+use super::enums::HealthType;
+
 // message: "Indexers unavailable due to failures: Nzb.su"
 // type: "warning"
 // wikiUrl: "https://github.com/Radarr/Radarr/wiki/Health-checks#indexers-are-unavailable-due-to-failures"
@@ -9,7 +11,7 @@ pub struct HealthResponse {
     pub message: String,
 
     #[serde(rename = "type")]
-    pub log_level: String,
+    pub log_level: HealthType,
 
     #[serde(rename = "wikiUrl")]
     pub wiki_url: String,
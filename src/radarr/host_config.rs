@@ -0,0 +1,117 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// `config/host`
+//
+// bindAddress: "*"
+// port: 7878
+// enableSsl: false
+// urlBase: ""
+// authenticationMethod: "none"
+// branch: "master"
+// backupInterval: 7
+// backupRetention: 28
+// id: 1
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct HostConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    #[serde(rename = "bindAddress", skip_serializing_if = "Option::is_none")]
+    pub bind_address: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u32>,
+
+    #[serde(rename = "sslPort", skip_serializing_if = "Option::is_none")]
+    pub ssl_port: Option<u32>,
+
+    #[serde(rename = "enableSsl", skip_serializing_if = "Option::is_none")]
+    pub enable_ssl: Option<bool>,
+
+    #[serde(rename = "launchBrowser", skip_serializing_if = "Option::is_none")]
+    pub launch_browser: Option<bool>,
+
+    #[serde(rename = "authenticationMethod", skip_serializing_if = "Option::is_none")]
+    pub authentication_method: Option<String>,
+
+    #[serde(rename = "analyticsEnabled", skip_serializing_if = "Option::is_none")]
+    pub analytics_enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    #[serde(rename = "logLevel", skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
+
+    #[serde(rename = "consoleLogLevel", skip_serializing_if = "Option::is_none")]
+    pub console_log_level: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+
+    #[serde(rename = "apiKey", skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+
+    #[serde(rename = "sslCertPath", skip_serializing_if = "Option::is_none")]
+    pub ssl_cert_path: Option<String>,
+
+    #[serde(rename = "sslCertPassword", skip_serializing_if = "Option::is_none")]
+    pub ssl_cert_password: Option<String>,
+
+    #[serde(rename = "urlBase", skip_serializing_if = "Option::is_none")]
+    pub url_base: Option<String>,
+
+    #[serde(rename = "updateAutomatically", skip_serializing_if = "Option::is_none")]
+    pub update_automatically: Option<bool>,
+
+    #[serde(rename = "updateMechanism", skip_serializing_if = "Option::is_none")]
+    pub update_mechanism: Option<String>,
+
+    #[serde(rename = "updateScriptPath", skip_serializing_if = "Option::is_none")]
+    pub update_script_path: Option<String>,
+
+    #[serde(rename = "proxyEnabled", skip_serializing_if = "Option::is_none")]
+    pub proxy_enabled: Option<bool>,
+
+    #[serde(rename = "proxyType", skip_serializing_if = "Option::is_none")]
+    pub proxy_type: Option<String>,
+
+    #[serde(rename = "proxyHostname", skip_serializing_if = "Option::is_none")]
+    pub proxy_hostname: Option<String>,
+
+    #[serde(rename = "proxyPort", skip_serializing_if = "Option::is_none")]
+    pub proxy_port: Option<u32>,
+
+    #[serde(rename = "proxyUsername", skip_serializing_if = "Option::is_none")]
+    pub proxy_username: Option<String>,
+
+    #[serde(rename = "proxyPassword", skip_serializing_if = "Option::is_none")]
+    pub proxy_password: Option<String>,
+
+    #[serde(rename = "proxyBypassFilter", skip_serializing_if = "Option::is_none")]
+    pub proxy_bypass_filter: Option<String>,
+
+    #[serde(rename = "proxyBypassLocalAddresses", skip_serializing_if = "Option::is_none")]
+    pub proxy_bypass_local_addresses: Option<bool>,
+
+    #[serde(rename = "certificateValidation", skip_serializing_if = "Option::is_none")]
+    pub certificate_validation: Option<String>,
+
+    #[serde(rename = "backupFolder", skip_serializing_if = "Option::is_none")]
+    pub backup_folder: Option<String>,
+
+    #[serde(rename = "backupInterval", skip_serializing_if = "Option::is_none")]
+    pub backup_interval: Option<u32>,
+
+    #[serde(rename = "backupRetention", skip_serializing_if = "Option::is_none")]
+    pub backup_retention: Option<u32>,
+
+    // anything newer versions of Radarr add, so updates don't drop it
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
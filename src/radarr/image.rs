@@ -1,9 +1,11 @@
 use serde::{Serialize, Deserialize};
 
+use super::enums::CoverType;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Image {
     #[serde(rename = "coverType")]
-    pub cover_type: String,
+    pub cover_type: CoverType,
 
     pub url: String,
 }
@@ -0,0 +1,48 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// `config/indexer`
+//
+// minimumAge: 0
+// maximumSize: 0
+// retention: 0
+// rssSyncInterval: 60
+// preferIndexerFlags: false
+// availabilityDelay: 0
+// allowHardcodedSubs: false
+// whitelistedHardcodedSubs: ""
+// id: 1
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct IndexerConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    #[serde(rename = "minimumAge", skip_serializing_if = "Option::is_none")]
+    pub minimum_age: Option<u32>,
+
+    #[serde(rename = "maximumSize", skip_serializing_if = "Option::is_none")]
+    pub maximum_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<u32>,
+
+    #[serde(rename = "rssSyncInterval", skip_serializing_if = "Option::is_none")]
+    pub rss_sync_interval: Option<u32>,
+
+    #[serde(rename = "preferIndexerFlags", skip_serializing_if = "Option::is_none")]
+    pub prefer_indexer_flags: Option<bool>,
+
+    #[serde(rename = "availabilityDelay", skip_serializing_if = "Option::is_none")]
+    pub availability_delay: Option<i32>,
+
+    #[serde(rename = "allowHardcodedSubs", skip_serializing_if = "Option::is_none")]
+    pub allow_hardcoded_subs: Option<bool>,
+
+    #[serde(rename = "whitelistedHardcodedSubs", skip_serializing_if = "Option::is_none")]
+    pub whitelisted_hardcoded_subs: Option<String>,
+
+    // anything newer versions of Radarr add, so updates don't drop it
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+
+// id: 1
+// name: "English"
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Language {
+    pub id: u32,
+    pub name: String,
+}
+
+impl Language {
+    // find a language by its ID or (case-insensitive) name
+    pub fn find<'a>(languages: &'a [Language], value: &str) -> Option<&'a Language> {
+        let id = value.parse::<u32>().ok();
+
+        languages.iter()
+            .find(|language| Some(language.id) == id || language.name.eq_ignore_ascii_case(value))
+    }
+}
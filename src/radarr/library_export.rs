@@ -0,0 +1,172 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use super::movie_response::MovieResponse;
+
+// Write tracked movies as csv for other sites to import:
+//
+// letterboxd: Title,Year,imdbID,tmdbID (the columns Letterboxd's importer recognizes)
+// imdb: Const,Title,Year (IMDb's list importer only needs the `Const` column)
+// csv: title,year,imdb_id,tmdb_id
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Letterboxd,
+    Imdb,
+    Csv,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<ExportFormat, String> {
+        match value {
+            "letterboxd" => Ok(ExportFormat::Letterboxd),
+            "imdb" => Ok(ExportFormat::Imdb),
+            "csv" => Ok(ExportFormat::Csv),
+            _ => Err(format!("Unknown export format: {}", value)),
+        }
+    }
+}
+
+// Which movies to export. `None` doesn't filter, a movie must have every tag in `tags`, and
+// `added_after` is inclusive while `added_before` isn't.
+#[derive(Debug, Default, Clone)]
+pub struct MovieFilter {
+    pub has_file: Option<bool>,
+    pub monitored: Option<bool>,
+    pub tags: Vec<u32>,
+    pub added_after: Option<DateTime<Utc>>,
+    pub added_before: Option<DateTime<Utc>>,
+}
+
+impl MovieFilter {
+    pub fn matches(&self, movie: &MovieResponse) -> bool {
+        self.has_file.is_none_or(|has_file| movie.has_file == has_file)
+            && self.monitored.is_none_or(|monitored| movie.monitored == monitored)
+            && self.tags.iter().all(|tag| movie.tags.contains(tag))
+            && self.added_after.is_none_or(|after| movie.added >= after)
+            && self.added_before.is_none_or(|before| movie.added < before)
+    }
+}
+
+pub fn export_movies<'a, I: IntoIterator<Item = &'a MovieResponse>>(format: ExportFormat, movies: I) -> String {
+    let header: &[&str] = match format {
+        ExportFormat::Letterboxd => &["Title", "Year", "imdbID", "tmdbID"],
+        ExportFormat::Imdb => &["Const", "Title", "Year"],
+        ExportFormat::Csv => &["title", "year", "imdb_id", "tmdb_id"],
+    };
+
+    let mut csv = csv_row(header.iter().map(|column| column.to_string()));
+
+    for movie in movies {
+        let imdb_id = movie.imdb_id.to_owned().unwrap_or_default();
+
+        // IMDb can't do anything with a movie it has no id for
+        if format == ExportFormat::Imdb && imdb_id.is_empty() {
+            continue;
+        }
+
+        let title = movie.title.to_owned();
+        let year = movie.year.to_string();
+        let tmdb_id = movie.tmdb_id.map(|id| id.to_string()).unwrap_or_default();
+
+        let row = match format {
+            ExportFormat::Imdb => vec![imdb_id, title, year],
+            ExportFormat::Letterboxd | ExportFormat::Csv => vec![title, year, imdb_id, tmdb_id],
+        };
+
+        csv.push_str(&csv_row(row));
+    }
+
+    csv
+}
+
+fn csv_row<I: IntoIterator<Item = String>>(fields: I) -> String {
+    let fields: Vec<String> = fields.into_iter().map(|field| csv_field(&field)).collect();
+
+    fields.join(",") + "\n"
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        String::from(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movie(title: &str, year: u32, imdb_id: Option<&str>, has_file: bool, tags: &[u32]) -> MovieResponse {
+        serde_json::from_value(serde_json::json!({
+            "title": title,
+            "sortTitle": title.to_lowercase(),
+            "status": "released",
+            "images": [],
+            "year": year,
+            "hasFile": has_file,
+            "path": format!("/movies/{}", title),
+            "monitored": true,
+            "minimumAvailability": "released",
+            "runtime": 100,
+            "cleanTitle": title.to_lowercase(),
+            "imdbId": imdb_id,
+            "tmdbId": year,
+            "titleSlug": title.to_lowercase(),
+            "genres": [],
+            "tags": tags,
+            "added": "2020-01-01T00:00:00Z",
+            "qualityProfileId": 1,
+            "id": 1,
+        })).unwrap()
+    }
+
+    #[test]
+    fn export_quotes_fields_and_skips_movies_imdb_cant_use() {
+        let movies = vec![
+            movie("Crouching Tiger, Hidden Dragon", 2000, Some("tt0190332"), true, &[]),
+            movie("The \"Burbs\"", 1989, None, false, &[]),
+        ];
+
+        assert_eq!(export_movies(ExportFormat::Letterboxd, &movies),
+                   "Title,Year,imdbID,tmdbID\n\
+                    \"Crouching Tiger, Hidden Dragon\",2000,tt0190332,2000\n\
+                    \"The \"\"Burbs\"\"\",1989,,1989\n");
+
+        assert_eq!(export_movies(ExportFormat::Imdb, &movies),
+                   "Const,Title,Year\n\
+                    tt0190332,\"Crouching Tiger, Hidden Dragon\",2000\n");
+    }
+
+    #[test]
+    fn filter_requires_every_condition() {
+        let downloaded = movie("Alien", 1979, None, true, &[1, 2]);
+        let missing = movie("Aliens", 1986, None, false, &[1]);
+
+        let filter = MovieFilter { has_file: Some(true), ..Default::default() };
+        assert!(filter.matches(&downloaded));
+        assert!(!filter.matches(&missing));
+
+        let filter = MovieFilter { tags: vec![1, 2], ..Default::default() };
+        assert!(filter.matches(&downloaded));
+        assert!(!filter.matches(&missing));
+
+        assert!(MovieFilter::default().matches(&missing));
+    }
+
+    #[test]
+    fn filter_by_added_date() {
+        // every test movie was added 2020-01-01
+        let alien = movie("Alien", 1979, None, true, &[]);
+        let date = |value| Some(crate::radarr::parse_datetime(value).unwrap());
+
+        assert!(MovieFilter { added_after: date("2020-01-01"), ..Default::default() }.matches(&alien));
+        assert!(!MovieFilter { added_after: date("2020-01-02"), ..Default::default() }.matches(&alien));
+        assert!(!MovieFilter { added_before: date("2020-01-01"), ..Default::default() }.matches(&alien));
+        assert!(MovieFilter { added_before: date("2020-01-02"), ..Default::default() }.matches(&alien));
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+// time: "2021-06-27T03:01:12.34Z"
+// level: "warn"
+// logger: "DownloadedMovieImportService"
+// message: "Couldn't import movie /downloads/foo.mkv: ..."
+// exception: "System.IO.IOException: ..."
+// exceptionType: "System.IO.IOException"
+// method: "Import"
+// id: 98765
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LogEntry {
+    #[serde(with = "super::datetime")]
+    pub time: DateTime<Utc>,
+
+    pub level: String,
+    pub logger: Option<String>,
+    pub message: Option<String>,
+    pub exception: Option<String>,
+
+    #[serde(rename = "exceptionType")]
+    pub exception_type: Option<String>,
+
+    pub method: Option<String>,
+    pub id: u32,
+}
+
+// filename: "radarr.txt"
+// lastWriteTime: "2021-06-27T03:01:12Z"
+// contentsUrl: "/api/v3/log/file/radarr.txt"
+// downloadUrl: "/logfile/radarr.txt"
+// id: 1
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LogFile {
+    pub filename: String,
+
+    #[serde(rename = "lastWriteTime", with = "super::datetime")]
+    pub last_write_time: DateTime<Utc>,
+
+    // relative to the server's url base
+    #[serde(rename = "contentsUrl")]
+    pub contents_url: String,
+
+    #[serde(rename = "downloadUrl")]
+    pub download_url: Option<String>,
+
+    pub id: u32,
+}
@@ -0,0 +1,187 @@
+use serde::{Serialize, Deserialize};
+
+use super::quality::Quality;
+use super::language::Language;
+
+// A candidate file returned by `GET manualimport?folder=...`:
+//
+// id: 1
+// path: "/downloads/Alien.1979.1080p.BluRay.x264/Alien.1979.1080p.BluRay.x264.mkv"
+// relativePath: "Alien.1979.1080p.BluRay.x264.mkv"
+// folderName: "Alien.1979.1080p.BluRay.x264"
+// name: "Alien.1979.1080p.BluRay.x264"
+// size: 8589934592
+// movie: { id: 12, title: "Alien", year: 1979, ... }
+// quality: { quality: { id: 7, name: "Bluray-1080p", ... }, revision: { ... } }
+// languages: [{ id: 1, name: "English" }]
+// releaseGroup: ""
+// downloadId: null
+// rejections: [{ reason: "Not an upgrade for existing movie file(s)", type: "permanent" }]
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ManualImportItem {
+    pub id: Option<u32>,
+    pub path: String,
+
+    #[serde(rename = "relativePath")]
+    pub relative_path: Option<String>,
+
+    #[serde(rename = "folderName")]
+    pub folder_name: Option<String>,
+
+    pub name: Option<String>,
+    pub size: u64,
+    pub movie: Option<ManualImportMovie>,
+    pub quality: Option<Quality>,
+
+    #[serde(default)]
+    pub languages: Vec<Language>,
+
+    #[serde(rename = "releaseGroup")]
+    pub release_group: Option<String>,
+
+    #[serde(rename = "downloadId")]
+    pub download_id: Option<String>,
+
+    #[serde(default)]
+    pub rejections: Vec<Rejection>,
+}
+
+impl ManualImportItem {
+    // a file can be imported once Radarr has matched it to a movie and found nothing to reject
+    pub fn is_importable(&self) -> bool {
+        self.movie.is_some() && self.rejections.is_empty()
+    }
+}
+
+// just enough of the movie to tell which one Radarr matched the file to
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ManualImportMovie {
+    pub id: u32,
+    pub title: String,
+    pub year: u32,
+
+    #[serde(rename = "tmdbId")]
+    pub tmdb_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Rejection {
+    pub reason: String,
+
+    #[serde(rename = "type")]
+    pub rejection_type: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportMode {
+    Auto,
+    Move,
+    Copy,
+}
+
+// A file to import as part of a `ManualImport` command
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ManualImportFile {
+    pub path: String,
+
+    #[serde(rename = "movieId")]
+    pub movie_id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<Quality>,
+
+    pub languages: Vec<Language>,
+
+    #[serde(rename = "releaseGroup", skip_serializing_if = "Option::is_none")]
+    pub release_group: Option<String>,
+
+    #[serde(rename = "downloadId", skip_serializing_if = "Option::is_none")]
+    pub download_id: Option<String>,
+}
+
+impl ManualImportFile {
+    pub fn from_item(item: &ManualImportItem) -> Option<ManualImportFile> {
+        let movie = item.movie.as_ref()?;
+
+        Some(ManualImportFile {
+            path: item.path.to_owned(),
+            movie_id: movie.id,
+            quality: item.quality.clone(),
+            languages: item.languages.to_vec(),
+            release_group: item.release_group.clone(),
+            download_id: item.download_id.clone(),
+        })
+    }
+}
+
+// Body for `POST command` that kicks off the import
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ManualImportPayload {
+    pub name: String,
+    pub files: Vec<ManualImportFile>,
+
+    #[serde(rename = "importMode")]
+    pub import_mode: ImportMode,
+}
+
+impl ManualImportPayload {
+    pub fn new(files: Vec<ManualImportFile>, import_mode: ImportMode) -> ManualImportPayload {
+        ManualImportPayload {
+            name: String::from("ManualImport"),
+            files,
+            import_mode,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(json: serde_json::Value) -> ManualImportItem {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn unmatched_files_are_not_importable() {
+        let item = item(serde_json::json!({
+            "path": "/downloads/foo.mkv",
+            "size": 1,
+            "rejections": [],
+        }));
+
+        assert!(!item.is_importable());
+        assert!(ManualImportFile::from_item(&item).is_none());
+    }
+
+    #[test]
+    fn rejected_files_are_not_importable() {
+        let item = item(serde_json::json!({
+            "path": "/downloads/Alien.1979.mkv",
+            "size": 1,
+            "movie": { "id": 12, "title": "Alien", "year": 1979 },
+            "rejections": [{ "reason": "Not an upgrade", "type": "permanent" }],
+        }));
+
+        assert!(!item.is_importable());
+    }
+
+    #[test]
+    fn payload_is_a_manual_import_command() {
+        let item = item(serde_json::json!({
+            "path": "/downloads/Alien.1979.mkv",
+            "size": 1,
+            "movie": { "id": 12, "title": "Alien", "year": 1979 },
+        }));
+        let file = ManualImportFile::from_item(&item).unwrap();
+        let payload = ManualImportPayload::new(vec![file], ImportMode::Copy);
+
+        assert_eq!(serde_json::to_value(&payload).unwrap(), serde_json::json!({
+            "name": "ManualImport",
+            "importMode": "copy",
+            "files": [{ "path": "/downloads/Alien.1979.mkv", "movieId": 12, "languages": [] }],
+        }));
+    }
+}
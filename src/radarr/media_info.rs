@@ -1,5 +1,24 @@
 use serde::{Serialize, Deserialize};
 
+// Radarr v3 sends a much smaller set of keys than older versions did, e.g.:
+//
+// audioBitrate: 1536000
+// audioChannels: 5.1
+// audioCodec: "DTS"
+// audioLanguages: "English"
+// audioStreamCount: 1
+// videoBitDepth: 8
+// videoBitrate: 0
+// videoCodec: "x264"
+// videoDynamicRangeType: ""
+// videoFps: 23.976
+// resolution: "1920x800"
+// runTime: "2:16:18"
+// scanType: "Progressive"
+// subtitles: "English"
+//
+// so everything here is optional.
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MediaInfo {
     #[serde(rename = "containerFormat")]
@@ -8,59 +27,75 @@ pub struct MediaInfo {
     #[serde(rename = "videoFormat")]
     pub video_format: Option<String>,
 
+    #[serde(rename = "videoCodec")]
+    pub video_codec: Option<String>,
+
     #[serde(rename = "videoCodecId")]
     pub video_codec_id: Option<String>,
 
     #[serde(rename = "videoProfile")]
     pub video_profile: Option<String>,
 
-    pub width: u32,
-    pub height: u32,
+    #[serde(rename = "videoBitDepth")]
+    pub video_bit_depth: Option<u32>,
+
+    #[serde(rename = "videoBitrate")]
+    pub video_bitrate: Option<u64>,
+
+    #[serde(rename = "videoDynamicRangeType")]
+    pub video_dynamic_range_type: Option<String>,
+
+    pub resolution: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+
+    #[serde(rename = "audioCodec")]
+    pub audio_codec: Option<String>,
 
     #[serde(rename = "audioFormat")]
-    pub audio_format: String,
+    pub audio_format: Option<String>,
 
     #[serde(rename = "audioCodecId")]
     pub audio_codec_id: Option<String>,
 
     #[serde(rename = "audioCodecLibrary")]
-    pub audio_codec_library: String,
+    pub audio_codec_library: Option<String>,
 
     #[serde(rename = "audioAdditionalFeatures")]
-    pub audio_additional_features: String,
+    pub audio_additional_features: Option<String>,
 
     #[serde(rename = "audioBitrate")]
-    pub audio_bitrate: u32,
+    pub audio_bitrate: Option<u64>,
 
     #[serde(rename = "runTime")]
-    pub run_time: String,
+    pub run_time: Option<String>,
 
     #[serde(rename = "audioStreamCount")]
-    pub audio_stream_count: u32,
+    pub audio_stream_count: Option<u32>,
 
     #[serde(rename = "audioChannels")]
-    pub audio_channels: f32,
+    pub audio_channels: Option<f32>,
 
     #[serde(rename = "audioChannelPositions")]
-    pub audio_channel_positions: String,
+    pub audio_channel_positions: Option<String>,
 
     #[serde(rename = "audioChannelPositionsText")]
-    pub audio_channel_positions_text: String,
+    pub audio_channel_positions_text: Option<String>,
 
     #[serde(rename = "audioProfile")]
-    pub audio_profile: String,
+    pub audio_profile: Option<String>,
 
     #[serde(rename = "videoFps")]
-    pub video_fps: f32,
+    pub video_fps: Option<f32>,
 
     #[serde(rename = "audioLanguages")]
-    pub audio_languages: String,
+    pub audio_languages: Option<String>,
 
-    pub subtitles: String,
+    pub subtitles: Option<String>,
 
     #[serde(rename = "scanType")]
-    pub scan_type: String,
+    pub scan_type: Option<String>,
 
     #[serde(rename = "schemaRevision")]
-    pub schema_revision: u32,
+    pub schema_revision: Option<u32>,
 }
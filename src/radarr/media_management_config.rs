@@ -0,0 +1,78 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// `config/mediamanagement`
+//
+// copyUsingHardlinks: true
+// importExtraFiles: false
+// extraFileExtensions: "srt"
+// recycleBin: ""
+// minimumFreeSpaceWhenImporting: 100
+// id: 1
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MediaManagementConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    #[serde(rename = "autoUnmonitorPreviouslyDownloadedMovies", skip_serializing_if = "Option::is_none")]
+    pub auto_unmonitor_previously_downloaded_movies: Option<bool>,
+
+    #[serde(rename = "recycleBin", skip_serializing_if = "Option::is_none")]
+    pub recycle_bin: Option<String>,
+
+    #[serde(rename = "recycleBinCleanupDays", skip_serializing_if = "Option::is_none")]
+    pub recycle_bin_cleanup_days: Option<u32>,
+
+    #[serde(rename = "downloadPropersAndRepacks", skip_serializing_if = "Option::is_none")]
+    pub download_propers_and_repacks: Option<String>,
+
+    #[serde(rename = "createEmptyMovieFolders", skip_serializing_if = "Option::is_none")]
+    pub create_empty_movie_folders: Option<bool>,
+
+    #[serde(rename = "deleteEmptyFolders", skip_serializing_if = "Option::is_none")]
+    pub delete_empty_folders: Option<bool>,
+
+    #[serde(rename = "fileDate", skip_serializing_if = "Option::is_none")]
+    pub file_date: Option<String>,
+
+    #[serde(rename = "rescanAfterRefresh", skip_serializing_if = "Option::is_none")]
+    pub rescan_after_refresh: Option<String>,
+
+    #[serde(rename = "autoRenameFolders", skip_serializing_if = "Option::is_none")]
+    pub auto_rename_folders: Option<bool>,
+
+    #[serde(rename = "pathsDefaultStatic", skip_serializing_if = "Option::is_none")]
+    pub paths_default_static: Option<bool>,
+
+    #[serde(rename = "setPermissionsLinux", skip_serializing_if = "Option::is_none")]
+    pub set_permissions_linux: Option<bool>,
+
+    #[serde(rename = "chmodFolder", skip_serializing_if = "Option::is_none")]
+    pub chmod_folder: Option<String>,
+
+    #[serde(rename = "chownGroup", skip_serializing_if = "Option::is_none")]
+    pub chown_group: Option<String>,
+
+    #[serde(rename = "skipFreeSpaceCheckWhenImporting", skip_serializing_if = "Option::is_none")]
+    pub skip_free_space_check_when_importing: Option<bool>,
+
+    #[serde(rename = "minimumFreeSpaceWhenImporting", skip_serializing_if = "Option::is_none")]
+    pub minimum_free_space_when_importing: Option<u32>,
+
+    #[serde(rename = "copyUsingHardlinks", skip_serializing_if = "Option::is_none")]
+    pub copy_using_hardlinks: Option<bool>,
+
+    #[serde(rename = "importExtraFiles", skip_serializing_if = "Option::is_none")]
+    pub import_extra_files: Option<bool>,
+
+    #[serde(rename = "extraFileExtensions", skip_serializing_if = "Option::is_none")]
+    pub extra_file_extensions: Option<String>,
+
+    #[serde(rename = "enableMediaInfo", skip_serializing_if = "Option::is_none")]
+    pub enable_media_info: Option<bool>,
+
+    // anything newer versions of Radarr add, so updates don't drop it
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
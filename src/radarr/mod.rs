@@ -3,19 +3,54 @@ mod config;
 mod client;
 mod status_response;
 mod health_response;
+mod system_task;
+mod update_response;
 mod root_folder_response;
+mod disk_space;
+mod root_folder_strategy;
+mod enums;
+mod datetime;
+mod title_match;
+mod movie_list;
+mod watchlist;
+mod showtimes;
+mod library_export;
 mod movie_response;
 mod rating;
 mod image;
 mod alternative_title;
 mod media_info;
 mod movie_file;
+mod movie_file_editor_payload;
+mod language;
 mod revision;
 mod quality;
 mod quality_spec;
+mod quality_definition;
+mod custom_format;
+mod quality_profile;
+mod provider;
+mod tag;
+mod collection;
+mod credit;
+mod delay_profile;
+mod release_profile;
+mod remote_path_mapping;
+mod settings;
+mod naming_config;
+mod media_management_config;
+mod host_config;
+mod ui_config;
+mod indexer_config;
 mod add_movie_payload;
 mod add_options;
-mod error;
+mod command_response;
+mod backup;
+mod page;
+mod log_entry;
+mod manual_import;
+mod rename_preview;
+pub mod error;
 mod data_source;
 
 mod response;
@@ -25,18 +60,53 @@ pub use config::Config;
 pub use client::Client;
 pub use status_response::StatusResponse;
 pub use health_response::HealthResponse;
-pub use root_folder_response::RootFolderResponse;
+pub use system_task::SystemTask;
+pub use update_response::{UpdateResponse, UpdateChanges};
+pub use root_folder_response::{RootFolderResponse, UnmappedFolder};
+pub use disk_space::DiskSpace;
+pub use root_folder_strategy::{RootFolderStrategy, RootFolderRules, RootFolderRule};
+pub use movie_list::{MovieRef, MovieListEntry, parse_movie_list};
+pub use watchlist::{ListFormat, parse_watchlist};
+pub use library_export::{ExportFormat, MovieFilter, export_movies};
+pub use showtimes::{ShowtimeSource, Showing, CinemaFeed, ShowingFilter};
+pub use datetime::{parse_datetime, relative_time};
+pub use enums::{MovieStatus, MinimumAvailability, CoverType, HealthType, MonitorMode};
+pub use title_match::{TitleQuery, TitlePick, MatchCandidate, MatchOutcome, best_match, normalize_title};
 pub use movie_response::MovieResponse;
 pub use rating::Rating;
 pub use image::Image;
 pub use alternative_title::AlternativeTitle;
 pub use media_info::MediaInfo;
 pub use movie_file::MovieFile;
+pub use movie_file_editor_payload::MovieFileEditorPayload;
+pub use language::Language;
 pub use revision::Revision;
 pub use quality::Quality;
 pub use quality_spec::QualitySpec;
+pub use quality_definition::{QualityDefinition, QualitySizeLimits};
+pub use custom_format::{CustomFormat, Specification, SpecificationKind};
+pub use quality_profile::QualityProfile;
+pub use provider::{Provider, ProviderField};
+pub use tag::Tag;
+pub use collection::{Collection, CollectionMovie};
+pub use credit::Credit;
+pub use delay_profile::DelayProfile;
+pub use release_profile::ReleaseProfile;
+pub use remote_path_mapping::RemotePathMapping;
+pub use settings::{InstanceSettings, SettingsChange, ChangeAction};
+pub use naming_config::{NamingConfig, NamingExamples};
+pub use media_management_config::MediaManagementConfig;
+pub use host_config::HostConfig;
+pub use ui_config::UiConfig;
+pub use indexer_config::IndexerConfig;
 pub use add_movie_payload::AddMoviePayload;
 pub use add_options::AddOptions;
+pub use command_response::CommandResponse;
+pub use backup::Backup;
+pub use page::Page;
+pub use log_entry::{LogEntry, LogFile};
+pub use rename_preview::{RenamePreview, RenameFilesPayload};
+pub use manual_import::{ManualImportItem, ManualImportMovie, ManualImportFile, ManualImportPayload, ImportMode, Rejection};
 pub use data_source::DataSource;
 
 pub use response::Response;
@@ -1,7 +1,9 @@
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 
 use super::media_info::MediaInfo;
 use super::quality::Quality;
+use super::language::Language;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MovieFile {
@@ -11,13 +13,31 @@ pub struct MovieFile {
     #[serde(rename = "relativePath")]
     pub relative_path: String,
 
+    pub path: Option<String>,
+
     pub size: u64,
 
-    #[serde(rename = "dateAdded")]
-    pub date_added: String, // datetime
+    #[serde(rename = "dateAdded", with = "super::datetime")]
+    pub date_added: DateTime<Utc>,
+
+    #[serde(rename = "sceneName")]
+    pub scene_name: Option<String>,
+
+    #[serde(rename = "releaseGroup")]
+    pub release_group: Option<String>,
 
     pub quality: Quality,
-    pub edition: String,
+
+    #[serde(default)]
+    pub languages: Vec<Language>,
+
+    #[serde(rename = "mediaInfo")]
+    pub media_info: Option<MediaInfo>,
+
+    #[serde(rename = "qualityCutoffNotMet")]
+    pub quality_cutoff_not_met: Option<bool>,
+
+    pub edition: Option<String>,
 
     pub id: u32,
 }
@@ -0,0 +1,81 @@
+use serde::{Serialize, Deserialize};
+
+use super::quality::Quality;
+use super::language::Language;
+
+// Body for `PUT moviefile/editor`. Only the fields that are set get sent, so
+// Radarr leaves everything else on the files untouched.
+//
+// movieFileIds (array) - ids of the files to update
+// quality (object) - optional
+// languages (array) - optional
+// edition (string) - optional
+// releaseGroup (string) - optional
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct MovieFileEditorPayload {
+    #[serde(rename = "movieFileIds")]
+    pub movie_file_ids: Vec<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<Quality>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub languages: Option<Vec<Language>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edition: Option<String>,
+
+    #[serde(rename = "releaseGroup", skip_serializing_if = "Option::is_none")]
+    pub release_group: Option<String>,
+}
+
+impl MovieFileEditorPayload {
+    pub fn new(movie_file_ids: Vec<u32>) -> MovieFileEditorPayload {
+        MovieFileEditorPayload {
+            movie_file_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_quality(&mut self, value: Quality) {
+        self.quality = Some(value);
+    }
+
+    pub fn set_languages(&mut self, value: Vec<Language>) {
+        self.languages = Some(value);
+    }
+
+    pub fn set_edition(&mut self, value: &str) {
+        self.edition = Some(String::from(value));
+    }
+
+    pub fn set_release_group(&mut self, value: &str) {
+        self.release_group = Some(String::from(value));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.quality.is_none()
+            && self.languages.is_none()
+            && self.edition.is_none()
+            && self.release_group.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_only_fields_that_are_set() {
+        let mut payload = MovieFileEditorPayload::new(vec![1, 2]);
+        payload.set_edition("Director's Cut");
+
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json, serde_json::json!({
+            "movieFileIds": [1, 2],
+            "edition": "Director's Cut",
+        }));
+    }
+}
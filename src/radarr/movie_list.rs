@@ -0,0 +1,247 @@
+use std::fmt;
+
+// A plain list of movies to add, one per line, as either:
+//
+//   Alien,1979                     title and year (csv or tab separated)
+//   "Crouching Tiger, Hidden Dragon",2000
+//   The Thing                      title on its own
+//   603                            TMDb id, or `tmdb:603`
+//   tt0133093                      IMDb id, or `imdb:tt0133093`
+//   "1917",2019                    a title that's just a number needs a year or quotes
+//
+// Blank lines and lines starting with `#` are skipped, as is a header row.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MovieRef {
+    Tmdb(u32),
+    Imdb(String),
+    Title { title: String, year: Option<u32> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovieListEntry {
+    // 1-based, for reporting back on each row
+    pub line: usize,
+    pub movie: MovieRef,
+}
+
+const HEADERS: [&str; 7] = ["title", "tmdb", "tmdbid", "tmdb_id", "imdb", "imdbid", "imdb_id"];
+
+pub fn parse_movie_list(data: &str) -> Vec<MovieListEntry> {
+    let mut entries = vec![];
+
+    for (index, line) in data.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // tabs if there are any, otherwise commas
+        let delimiter = if line.contains('\t') { '\t' } else { ',' };
+        let fields = match csv_records(line, delimiter).pop() {
+            Some((_, fields)) => fields,
+            None => continue,
+        };
+
+        if index == 0 && HEADERS.contains(&fields[0].0.to_lowercase().as_str()) {
+            continue;
+        }
+
+        if let Some(movie) = parse_fields(&fields) {
+            entries.push(MovieListEntry { line: index + 1, movie });
+        }
+    }
+
+    entries
+}
+
+fn parse_fields(fields: &[(String, bool)]) -> Option<MovieRef> {
+    let (first, quoted) = &fields[0];
+
+    if fields.len() == 1 && !quoted {
+        if let Some(id) = parse_id(first) {
+            return Some(id);
+        }
+    }
+
+    // an unquoted title with commas in it ends up split, so glue it back together
+    let (title_fields, year) = match fields.split_last() {
+        Some(((last, _), rest)) if !rest.is_empty() && is_year(last) => (rest, last.parse().ok()),
+        _ => (fields, None),
+    };
+
+    let title = title_fields.iter()
+        .map(|(field, _)| field.as_str())
+        .filter(|field| !field.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if title.is_empty() {
+        None
+    } else {
+        Some(MovieRef::Title { title, year })
+    }
+}
+
+fn parse_id(field: &str) -> Option<MovieRef> {
+    let lowercase = field.to_lowercase();
+
+    if let Some(id) = lowercase.strip_prefix("tmdb:") {
+        return id.trim().parse().ok().map(MovieRef::Tmdb);
+    }
+
+    let imdb_id = lowercase.strip_prefix("imdb:").unwrap_or(&lowercase).trim();
+    if imdb_id.len() > 2 && imdb_id.starts_with("tt") && imdb_id[2..].chars().all(|c| c.is_ascii_digit()) {
+        return Some(MovieRef::Imdb(imdb_id.to_string()));
+    }
+
+    if !field.is_empty() && field.chars().all(|c| c.is_ascii_digit()) {
+        return field.parse().ok().map(MovieRef::Tmdb);
+    }
+
+    None
+}
+
+fn is_year(field: &str) -> bool {
+    field.len() == 4 && field.chars().all(|c| c.is_ascii_digit())
+}
+
+// rfc 4180 csv, with any delimiter: a field that starts with a double quote may contain the
+// delimiter, newlines and doubled quotes, and a quote anywhere else is just a quote. Fields come
+// back trimmed, along with whether they were quoted. Each record comes with the line it starts on
+// and blank ones are skipped.
+pub fn csv_records(data: &str, delimiter: char) -> Vec<(usize, Vec<(String, bool)>)> {
+    let data = data.trim_start_matches('\u{feff}');
+
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = data.chars().peekable();
+
+    let is_blank = |record: &[(String, bool)]| record.iter().all(|(field, quoted)| field.is_empty() && !quoted);
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            '"' if in_quotes => in_quotes = false,
+            '"' if !quoted && field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+                quoted = true;
+            },
+            '\r' if !in_quotes => {},
+            '\n' if !in_quotes => {
+                record.push((field.trim().to_string(), quoted));
+                field.clear();
+                quoted = false;
+
+                if is_blank(&record) {
+                    record.clear();
+                } else {
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+
+                line += 1;
+                record_line = line;
+            },
+            c if c == delimiter && !in_quotes => {
+                record.push((field.trim().to_string(), quoted));
+                field.clear();
+                quoted = false;
+            },
+            c => {
+                if c == '\n' {
+                    line += 1;
+                }
+
+                field.push(c);
+            },
+        }
+    }
+
+    record.push((field.trim().to_string(), quoted));
+    if !is_blank(&record) {
+        records.push((record_line, record));
+    }
+
+    records
+}
+
+impl fmt::Display for MovieRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MovieRef::Tmdb(id) => write!(f, "tmdb:{}", id),
+            MovieRef::Imdb(id) => write!(f, "imdb:{}", id),
+            MovieRef::Title { title, year: Some(year) } => write!(f, "{} ({})", title, year),
+            MovieRef::Title { title, year: None } => write!(f, "{}", title),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn title(title: &str, year: Option<u32>) -> MovieRef {
+        MovieRef::Title { title: String::from(title), year }
+    }
+
+    #[test]
+    fn parse_movie_list_handles_every_kind_of_row() {
+        let data = "title,year\n\
+                    Alien,1979\n\
+                    \n\
+                    # a comment\n\
+                    The Thing\n\
+                    603\n\
+                    tmdb:604\n\
+                    tt0133093\n\
+                    IMDB:tt0234215\n\
+                    \"Crouching Tiger, Hidden Dragon\",2000\n\
+                    Crouching Tiger, Hidden Dragon,2000\n\
+                    \"1917\"\n\
+                    Heat\t1995\n";
+
+        let movies: Vec<(usize, MovieRef)> = parse_movie_list(data).into_iter()
+            .map(|entry| (entry.line, entry.movie))
+            .collect();
+
+        assert_eq!(movies, vec![
+            (2, title("Alien", Some(1979))),
+            (5, title("The Thing", None)),
+            (6, MovieRef::Tmdb(603)),
+            (7, MovieRef::Tmdb(604)),
+            (8, MovieRef::Imdb(String::from("tt0133093"))),
+            (9, MovieRef::Imdb(String::from("tt0234215"))),
+            (10, title("Crouching Tiger, Hidden Dragon", Some(2000))),
+            (11, title("Crouching Tiger, Hidden Dragon", Some(2000))),
+            (12, title("1917", None)),
+            (13, title("Heat", Some(1995))),
+        ]);
+    }
+
+    #[test]
+    fn csv_records_unescapes_quotes() {
+        assert_eq!(csv_records(r#""Say ""Hi""", 2001"#, ','), vec![
+            (1, vec![(String::from(r#"Say "Hi""#), true), (String::from("2001"), false)]),
+        ]);
+    }
+
+    #[test]
+    fn csv_records_only_quote_whole_fields() {
+        let data = "12\" Vinyl,\"A, B\"\n\n\"multi\nline\",x\n";
+
+        assert_eq!(csv_records(data, ','), vec![
+            (1, vec![(String::from("12\" Vinyl"), false), (String::from("A, B"), true)]),
+            (3, vec![(String::from("multi\nline"), true), (String::from("x"), false)]),
+        ]);
+    }
+}
@@ -1,6 +1,7 @@
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 
-use super::rating::Rating;
+use super::enums::{MovieStatus, MinimumAvailability};
 use super::image::Image;
 use super::alternative_title::AlternativeTitle;
 use super::movie_file::MovieFile;
@@ -67,14 +68,22 @@ pub struct MovieResponse {
     #[serde(rename = "sizeOnDisk")]
     pub size_on_disk: Option<u64>,
 
-    pub status: String,
+    pub status: MovieStatus,
     pub overview: Option<String>,
 
-    #[serde(rename = "inCinemas")]
-    pub in_cinemas: Option<String>, // datetime
+    #[serde(rename = "inCinemas", default, with = "super::datetime::option")]
+    pub in_cinemas: Option<DateTime<Utc>>,
+
+    #[serde(rename = "physicalRelease", default, with = "super::datetime::option", skip_serializing_if = "Option::is_none")]
+    pub physical_release: Option<DateTime<Utc>>,
+
+    #[serde(rename = "digitalRelease", default, with = "super::datetime::option", skip_serializing_if = "Option::is_none")]
+    pub digital_release: Option<DateTime<Utc>>,
 
     pub images: Vec<Image>,
     pub website: Option<String>,
+    // only sent by older versions
+    #[serde(default)]
     pub downloaded: bool,
     pub year: u32,
 
@@ -87,18 +96,18 @@ pub struct MovieResponse {
     pub studio: Option<String>,
     pub path: String,
 
-    #[serde(rename = "profileId")]
+    #[serde(rename = "profileId", default)]
     pub profile_id: u32,
 
     pub monitored: bool,
 
     #[serde(rename = "minimumAvailability")]
-    pub minimum_availability: String,
+    pub minimum_availability: MinimumAvailability,
 
     pub runtime: u32,
 
-    #[serde(rename = "lastInfoSync")]
-    pub last_info_sync: Option<String>, // datetime
+    #[serde(rename = "lastInfoSync", default, with = "super::datetime::option")]
+    pub last_info_sync: Option<DateTime<Utc>>,
 
     #[serde(rename = "cleanTitle")]
     pub clean_title: String,
@@ -113,10 +122,13 @@ pub struct MovieResponse {
     pub title_slug: String,
 
     pub genres: Vec<String>,
-    pub tags: Vec<String>,
-    pub added: String, // datetime
+    #[serde(default)]
+    pub tags: Vec<u32>,
+
+    #[serde(with = "super::datetime")]
+    pub added: DateTime<Utc>,
 
-    #[serde(rename = "alternativeTitles")]
+    #[serde(rename = "alternativeTitles", default)]
     pub alternative_titles: Vec<AlternativeTitle>,
 
     #[serde(rename = "qualityProfileId")]
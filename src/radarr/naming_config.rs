@@ -0,0 +1,62 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// `config/naming`
+//
+// renameMovies: true
+// replaceIllegalCharacters: true
+// colonReplacementFormat: "smart"
+// standardMovieFormat: "{Movie Title} ({Release Year}) {Quality Full}"
+// movieFolderFormat: "{Movie Title} ({Release Year})"
+// id: 1
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct NamingConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    #[serde(rename = "renameMovies", skip_serializing_if = "Option::is_none")]
+    pub rename_movies: Option<bool>,
+
+    #[serde(rename = "replaceIllegalCharacters", skip_serializing_if = "Option::is_none")]
+    pub replace_illegal_characters: Option<bool>,
+
+    #[serde(rename = "colonReplacementFormat", skip_serializing_if = "Option::is_none")]
+    pub colon_replacement_format: Option<String>,
+
+    #[serde(rename = "standardMovieFormat", skip_serializing_if = "Option::is_none")]
+    pub standard_movie_format: Option<String>,
+
+    #[serde(rename = "movieFolderFormat", skip_serializing_if = "Option::is_none")]
+    pub movie_folder_format: Option<String>,
+
+    #[serde(rename = "includeQuality", skip_serializing_if = "Option::is_none")]
+    pub include_quality: Option<bool>,
+
+    #[serde(rename = "replaceSpaces", skip_serializing_if = "Option::is_none")]
+    pub replace_spaces: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub separator: Option<String>,
+
+    #[serde(rename = "numberStyle", skip_serializing_if = "Option::is_none")]
+    pub number_style: Option<String>,
+
+    // anything newer versions of Radarr add, so updates don't drop it
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+// `config/naming/examples`, rendered with the naming settings passed along
+//
+// movieExample: "The Movie Title (2010) Bluray-1080p Proper"
+// movieFolderExample: "The Movie Title (2010)"
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NamingExamples {
+    #[serde(rename = "movieExample")]
+    pub movie_example: Option<String>,
+
+    #[serde(rename = "movieFolderExample")]
+    pub movie_folder_example: Option<String>,
+}
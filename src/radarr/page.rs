@@ -0,0 +1,29 @@
+use serde::{Serialize, Deserialize};
+
+// Paged list endpoints (`log`, `history`, `queue`, ...) wrap their records like:
+//
+// page: 1
+// pageSize: 50
+// sortKey: "time"
+// sortDirection: "descending"
+// totalRecords: 1234
+// records: [...]
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Page<T> {
+    pub page: u32,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: u32,
+
+    #[serde(rename = "sortKey")]
+    pub sort_key: Option<String>,
+
+    #[serde(rename = "sortDirection")]
+    pub sort_direction: Option<String>,
+
+    #[serde(rename = "totalRecords")]
+    pub total_records: u32,
+
+    pub records: Vec<T>,
+}
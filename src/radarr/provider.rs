@@ -0,0 +1,44 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// Indexers, download clients and notifications are all "providers" in Radarr and share a shape:
+//
+// id: 2
+// name: "NZBGeek"
+// implementation: "Newznab"
+// implementationName: "Newznab"
+// configContract: "NewznabSettings"
+// enableRss: true
+// fields: [{ order: 0, name: "baseUrl", label: "URL", value: "https://api.nzbgeek.info", ... }, ...]
+// tags: [1]
+//
+// Only the common bits are typed. The rest is kept as-is so providers round-trip through updates.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Provider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    pub name: String,
+    pub implementation: String,
+
+    #[serde(default)]
+    pub fields: Vec<ProviderField>,
+
+    #[serde(default)]
+    pub tags: Vec<u32>,
+
+    #[serde(flatten)]
+    pub settings: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProviderField {
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub value: Value,
+
+    #[serde(flatten)]
+    pub metadata: Map<String, Value>,
+}
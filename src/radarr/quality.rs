@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 use super::quality_spec::QualitySpec;
 use super::revision::Revision;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Quality {
     pub quality: QualitySpec,
 
@@ -12,3 +12,13 @@ pub struct Quality {
 
     pub revision: Revision,
 }
+
+impl Quality {
+    pub fn new(quality: QualitySpec) -> Quality {
+        Quality {
+            quality,
+            custom_formats: None,
+            revision: Revision::default(),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use serde::{Serialize, Deserialize};
+
+use super::quality_spec::QualitySpec;
+
+// quality: { id: 7, name: "Bluray-1080p", source: "bluray", resolution: 1080, modifier: "none" }
+// title: "Bluray-1080p"
+// weight: 21
+// minSize: 0
+// maxSize: 400
+// preferredSize: 95
+// id: 7
+//
+// sizes are in MB per minute of runtime.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QualityDefinition {
+    pub quality: QualitySpec,
+    pub title: String,
+    pub weight: u32,
+
+    #[serde(rename = "minSize")]
+    pub min_size: Option<f64>,
+
+    #[serde(rename = "maxSize")]
+    pub max_size: Option<f64>,
+
+    #[serde(rename = "preferredSize")]
+    pub preferred_size: Option<f64>,
+
+    pub id: u32,
+}
+
+// Just the size limits of a definition, keyed by quality name. This is what gets exported to
+// and imported from files:
+//
+// { "quality": "Bluray-1080p", "minSize": 0, "maxSize": 400, "preferredSize": 95 }
+//
+// A missing (or null) max/preferred size means unlimited.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QualitySizeLimits {
+    pub quality: String,
+
+    #[serde(rename = "minSize")]
+    pub min_size: Option<f64>,
+
+    #[serde(rename = "maxSize")]
+    pub max_size: Option<f64>,
+
+    #[serde(rename = "preferredSize")]
+    pub preferred_size: Option<f64>,
+}
+
+impl QualityDefinition {
+    // find a definition by its quality's ID or (case-insensitive) name
+    pub fn find<'a>(definitions: &'a [QualityDefinition], value: &str) -> Option<&'a QualityDefinition> {
+        let id = value.parse::<u32>().ok();
+
+        definitions.iter()
+            .find(|definition| Some(definition.quality.id) == id || definition.quality.name.eq_ignore_ascii_case(value))
+    }
+
+    pub fn limits(&self) -> QualitySizeLimits {
+        QualitySizeLimits {
+            quality: self.quality.name.to_owned(),
+            min_size: self.min_size,
+            max_size: self.max_size,
+            preferred_size: self.preferred_size,
+        }
+    }
+
+    // take on the given limits, returning whether anything changed
+    pub fn set_limits(&mut self, limits: &QualitySizeLimits) -> bool {
+        let changed = self.min_size != limits.min_size
+            || self.max_size != limits.max_size
+            || self.preferred_size != limits.preferred_size;
+
+        self.min_size = limits.min_size;
+        self.max_size = limits.max_size;
+        self.preferred_size = limits.preferred_size;
+
+        changed
+    }
+}
+
+impl QualitySizeLimits {
+    // Radarr rejects limits that aren't ordered min <= preferred <= max
+    pub fn is_valid(&self) -> bool {
+        let min = self.min_size.unwrap_or(0.0);
+        let max = self.max_size.unwrap_or(f64::INFINITY);
+        let preferred = self.preferred_size.unwrap_or(max);
+
+        min >= 0.0 && min <= preferred && preferred <= max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definitions() -> Vec<QualityDefinition> {
+        serde_json::from_value(serde_json::json!([
+            {
+                "quality": { "id": 7, "name": "Bluray-1080p", "source": "bluray", "resolution": 1080, "modifier": "none" },
+                "title": "Bluray-1080p", "weight": 21, "minSize": 0, "maxSize": 400, "preferredSize": 95, "id": 7,
+            },
+            {
+                "quality": { "id": 19, "name": "Bluray-2160p", "source": "bluray", "resolution": 2160, "modifier": "none" },
+                "title": "Bluray-2160p", "weight": 29, "minSize": 0, "maxSize": null, "preferredSize": null, "id": 19,
+            },
+        ])).unwrap()
+    }
+
+    #[test]
+    fn find_by_id_or_name() {
+        let definitions = definitions();
+
+        assert_eq!(QualityDefinition::find(&definitions, "19").unwrap().id, 19);
+        assert_eq!(QualityDefinition::find(&definitions, "bluray-1080P").unwrap().id, 7);
+        assert!(QualityDefinition::find(&definitions, "nope").is_none());
+    }
+
+    #[test]
+    fn set_limits_reports_changes() {
+        let mut definition = definitions().remove(0);
+        let mut limits = definition.limits();
+
+        assert!(!definition.set_limits(&limits));
+
+        limits.max_size = Some(200.0);
+        assert!(definition.set_limits(&limits));
+        assert_eq!(definition.max_size, Some(200.0));
+    }
+
+    #[test]
+    fn limits_must_be_ordered() {
+        let limits = |min, preferred, max| QualitySizeLimits {
+            quality: String::from("Bluray-1080p"),
+            min_size: min,
+            max_size: max,
+            preferred_size: preferred,
+        };
+
+        assert!(limits(Some(0.0), Some(95.0), Some(400.0)).is_valid());
+        assert!(limits(Some(10.0), None, None).is_valid());
+        assert!(!limits(Some(100.0), Some(95.0), Some(400.0)).is_valid());
+        assert!(!limits(Some(0.0), Some(500.0), Some(400.0)).is_valid());
+    }
+}
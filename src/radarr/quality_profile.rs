@@ -0,0 +1,25 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// id: 6
+// name: "HD-1080p"
+// upgradeAllowed: true
+// cutoff: 7
+// items: [{ quality: { id: 7, name: "Bluray-1080p", ... }, items: [], allowed: true }, ...]
+// minFormatScore: 0
+// cutoffFormatScore: 0
+// formatItems: [{ format: 3, name: "x265", score: 10 }, ...]
+// language: { id: 1, name: "English" }
+//
+// Only the id and name are typed. The rest is kept as-is so profiles round-trip through updates.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QualityProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    pub name: String,
+
+    #[serde(flatten)]
+    pub settings: Map<String, Value>,
+}
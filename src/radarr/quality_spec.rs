@@ -1,9 +1,10 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QualitySpec {
     pub id: u32,
     pub name: String,
+    pub source: Option<String>,
     pub resolution: u16,
     pub modifier: String,
 }
@@ -0,0 +1,147 @@
+use serde::{Serialize, Deserialize};
+
+// name: "No HDTV"
+// enabled: true
+// required: ["x264", "x265"]
+// ignored: ["HDTV"]
+// indexerId: 0
+// tags: []
+// id: 1
+//
+// Older versions send `required`/`ignored` as a single comma separated string, which is accepted
+// too. `indexerId` 0 means every indexer.
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(from = "RawReleaseProfile", into = "RawReleaseProfile")]
+pub struct ReleaseProfile {
+    pub id: Option<u32>,
+    pub name: Option<String>,
+    pub enabled: bool,
+    pub required: Vec<String>,
+    pub ignored: Vec<String>,
+    pub indexer_id: u32,
+    pub tags: Vec<u32>,
+
+    // whether the terms were read as joined strings, so they're sent back the same way
+    pub joined_terms: bool,
+}
+
+// The wire format. `required`/`ignored` are either shape described above.
+#[derive(Serialize, Deserialize)]
+struct RawReleaseProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<u32>,
+
+    name: Option<String>,
+
+    #[serde(default = "enabled_by_default")]
+    enabled: bool,
+
+    #[serde(default)]
+    required: Option<Terms>,
+
+    #[serde(default)]
+    ignored: Option<Terms>,
+
+    #[serde(rename = "indexerId", default)]
+    indexer_id: u32,
+
+    #[serde(default)]
+    tags: Vec<u32>,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Terms {
+    List(Vec<String>),
+    Joined(String),
+}
+
+impl Terms {
+    fn is_joined(terms: &Option<Terms>) -> bool {
+        matches!(terms, Some(Terms::Joined(_)))
+    }
+
+    fn split(terms: Option<Terms>) -> Vec<String> {
+        match terms {
+            Some(Terms::List(terms)) => terms,
+            Some(Terms::Joined(terms)) => terms.split(',')
+                .map(|term| term.trim().to_string())
+                .filter(|term| !term.is_empty())
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    fn new(terms: Vec<String>, joined: bool) -> Option<Terms> {
+        if joined {
+            Some(Terms::Joined(terms.join(",")))
+        } else {
+            Some(Terms::List(terms))
+        }
+    }
+}
+
+impl From<RawReleaseProfile> for ReleaseProfile {
+    fn from(raw: RawReleaseProfile) -> ReleaseProfile {
+        ReleaseProfile {
+            joined_terms: Terms::is_joined(&raw.required) || Terms::is_joined(&raw.ignored),
+            id: raw.id,
+            name: raw.name,
+            enabled: raw.enabled,
+            required: Terms::split(raw.required),
+            ignored: Terms::split(raw.ignored),
+            indexer_id: raw.indexer_id,
+            tags: raw.tags,
+        }
+    }
+}
+
+impl From<ReleaseProfile> for RawReleaseProfile {
+    fn from(profile: ReleaseProfile) -> RawReleaseProfile {
+        RawReleaseProfile {
+            id: profile.id,
+            name: profile.name,
+            enabled: profile.enabled,
+            required: Terms::new(profile.required, profile.joined_terms),
+            ignored: Terms::new(profile.ignored, profile.joined_terms),
+            indexer_id: profile.indexer_id,
+            tags: profile.tags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terms_can_be_lists_or_joined_strings() {
+        let profile: ReleaseProfile = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "required": ["x264", "x265"],
+            "ignored": "HDTV, CAM,",
+        })).unwrap();
+
+        assert_eq!(profile.required, vec!["x264", "x265"]);
+        assert_eq!(profile.ignored, vec!["HDTV", "CAM"]);
+        assert!(profile.enabled);
+    }
+
+    #[test]
+    fn terms_are_sent_back_in_the_shape_they_came_in() {
+        let joined: ReleaseProfile = serde_json::from_value(serde_json::json!({ "id": 1, "required": "x264,x265", "ignored": "" })).unwrap();
+        let json = serde_json::to_value(&joined).unwrap();
+        assert_eq!(json["required"], "x264,x265");
+        assert_eq!(json["ignored"], "");
+
+        let listed: ReleaseProfile = serde_json::from_value(serde_json::json!({ "id": 1, "required": ["x264"] })).unwrap();
+        let json = serde_json::to_value(&listed).unwrap();
+        assert_eq!(json["required"], serde_json::json!(["x264"]));
+        assert_eq!(json["ignored"], serde_json::json!([]));
+    }
+}
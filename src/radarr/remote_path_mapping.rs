@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+
+// host: "seedbox.example.com"
+// remotePath: "/home/user/downloads/"
+// localPath: "/mnt/seedbox/downloads/"
+// id: 1
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemotePathMapping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    pub host: String,
+
+    #[serde(rename = "remotePath")]
+    pub remote_path: String,
+
+    #[serde(rename = "localPath")]
+    pub local_path: String,
+}
@@ -0,0 +1,69 @@
+use serde::{Serialize, Deserialize};
+
+// movieId: 12
+// movieFileId: 34
+// existingPath: "Alien.1979.1080p.BluRay.x264.mkv"
+// newPath: "Alien (1979) Bluray-1080p.mkv"
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RenamePreview {
+    #[serde(rename = "movieId")]
+    pub movie_id: u32,
+
+    #[serde(rename = "movieFileId")]
+    pub movie_file_id: u32,
+
+    #[serde(rename = "existingPath")]
+    pub existing_path: String,
+
+    #[serde(rename = "newPath")]
+    pub new_path: String,
+}
+
+// Body for the `RenameFiles` command
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RenameFilesPayload {
+    pub name: String,
+
+    #[serde(rename = "movieId")]
+    pub movie_id: u32,
+
+    pub files: Vec<u32>,
+}
+
+impl RenameFilesPayload {
+    pub fn new(movie_id: u32, files: Vec<u32>) -> RenameFilesPayload {
+        RenameFilesPayload {
+            name: String::from("RenameFiles"),
+            movie_id,
+            files,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_deserializes_from_the_api_shape() {
+        let previews: Vec<RenamePreview> = serde_json::from_value(serde_json::json!([{
+            "movieId": 12,
+            "movieFileId": 34,
+            "existingPath": "Alien.1979.1080p.BluRay.x264.mkv",
+            "newPath": "Alien (1979) Bluray-1080p.mkv",
+        }])).unwrap();
+
+        assert_eq!(previews.len(), 1);
+        assert_eq!((previews[0].movie_id, previews[0].movie_file_id), (12, 34));
+        assert_eq!(previews[0].existing_path, "Alien.1979.1080p.BluRay.x264.mkv");
+        assert_eq!(previews[0].new_path, "Alien (1979) Bluray-1080p.mkv");
+    }
+
+    #[test]
+    fn rename_files_payload_names_the_command() {
+        let payload = serde_json::to_value(RenameFilesPayload::new(12, vec![34, 35])).unwrap();
+
+        assert_eq!(payload, serde_json::json!({ "name": "RenameFiles", "movieId": 12, "files": [34, 35] }));
+    }
+}
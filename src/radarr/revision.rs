@@ -1,7 +1,20 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Revision {
     pub version: u32,
     pub real: u32,
+
+    #[serde(rename = "isRepack", default)]
+    pub is_repack: bool,
+}
+
+impl Default for Revision {
+    fn default() -> Revision {
+        Revision {
+            version: 1,
+            real: 0,
+            is_repack: false,
+        }
+    }
 }
@@ -1,15 +1,32 @@
 use serde::{Serialize, Deserialize};
 
+// accessible: true
 // freeSpace: 1245057187328
 // id: 1
 // path: "/storage/Movies/"
 // totalSpace: 46891755717632
+// unmappedFolders: [{ name: "Some Movie (2001)", path: "/storage/Movies/Some Movie (2001)" }]
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RootFolderResponse {
     #[serde(rename = "freeSpace")]
     pub free_space: u64,
 
+    #[serde(rename = "totalSpace")]
+    pub total_space: Option<u64>,
+
+    pub accessible: Option<bool>,
+
+    #[serde(rename = "unmappedFolders", default)]
+    pub unmapped_folders: Vec<UnmappedFolder>,
+
     pub id: u32,
     pub path: String,
 }
+
+// a folder inside a root folder that doesn't belong to any tracked movie
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnmappedFolder {
+    pub name: String,
+    pub path: String,
+}
@@ -0,0 +1,205 @@
+use serde::{Serialize, Deserialize};
+
+use std::error::Error;
+use std::fs;
+
+use super::root_folder_response::RootFolderResponse;
+use super::error;
+
+const BYTES_PER_GB: u64 = 1024 * 1024 * 1024;
+
+// How to pick the root folder a newly added movie goes into.
+#[derive(Debug)]
+pub enum RootFolderStrategy {
+    // the first root folder Radarr lists
+    First,
+
+    // the root folder with the most free space
+    MostFreeSpace,
+
+    // the first root folder with at least this many GB free
+    MinimumFreeSpace(u64),
+
+    // the first matching rule, by genre and/or tag
+    Rules(RootFolderRules),
+
+    // exactly this path, which must be a known root folder
+    Explicit(String),
+}
+
+// Rules for `RootFolderStrategy::Rules`, usually loaded from a json file:
+//
+// {
+//   "rules": [
+//     { "genre": "Horror", "path": "/storage/Horror/" },
+//     { "tag": "kids", "path": "/storage/Kids/" },
+//     { "genre": "Animation", "tag": "kids", "path": "/storage/Kids/Animated/" }
+//   ],
+//   "default": "/storage/Movies/"
+// }
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct RootFolderRules {
+    pub rules: Vec<RootFolderRule>,
+    pub default: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RootFolderRule {
+    pub genre: Option<String>,
+    pub tag: Option<String>,
+    pub path: String,
+}
+
+impl RootFolderRules {
+    pub fn from_file(file_path: &str) -> Result<RootFolderRules, Box<dyn Error>> {
+        let contents = fs::read_to_string(file_path)?;
+
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    // the path of the first rule matching the movie, or the default if none do
+    pub fn path_for(&self, genres: &[String], tags: &[String]) -> Option<&str> {
+        self.rules.iter()
+            .find(|rule| rule.matches(genres, tags))
+            .map(|rule| rule.path.as_str())
+            .or(self.default.as_deref())
+    }
+}
+
+impl RootFolderRule {
+    // a rule matches when every criterion it specifies matches. A rule with neither a genre nor a
+    // tag matches everything.
+    pub fn matches(&self, genres: &[String], tags: &[String]) -> bool {
+        let contains = |values: &[String], wanted: &Option<String>| {
+            match wanted {
+                Some(wanted) => values.iter().any(|value| value.eq_ignore_ascii_case(wanted)),
+                None => true,
+            }
+        };
+
+        contains(genres, &self.genre) && contains(tags, &self.tag)
+    }
+}
+
+impl RootFolderStrategy {
+    // pick a root folder for a movie with the given genres and tags
+    pub fn select<'a>(&self, root_folders: &'a [RootFolderResponse], genres: &[String], tags: &[String]) -> Result<&'a RootFolderResponse, error::NoRootFolder> {
+        if root_folders.is_empty() {
+            return Err(error::NoRootFolder::with_msg("Radarr has no root folders configured"));
+        }
+
+        match self {
+            RootFolderStrategy::First => Ok(&root_folders[0]),
+            RootFolderStrategy::MostFreeSpace => {
+                Ok(root_folders.iter().max_by_key(|folder| folder.free_space).unwrap())
+            },
+            RootFolderStrategy::MinimumFreeSpace(gb) => {
+                root_folders.iter()
+                    .find(|folder| folder.free_space >= gb * BYTES_PER_GB)
+                    .ok_or_else(|| error::NoRootFolder::with_msg(&format!("no root folder has at least {} GB free", gb)))
+            },
+            RootFolderStrategy::Rules(rules) => {
+                let path = rules.path_for(genres, tags)
+                    .ok_or_else(|| error::NoRootFolder::with_msg("no root folder rule matched and no default is set"))?;
+
+                find_by_path(root_folders, path)
+            },
+            RootFolderStrategy::Explicit(path) => find_by_path(root_folders, path),
+        }
+    }
+}
+
+// trailing slashes are optional when comparing paths
+fn find_by_path<'a>(root_folders: &'a [RootFolderResponse], path: &str) -> Result<&'a RootFolderResponse, error::NoRootFolder> {
+    let path = path.trim_end_matches(['/', '\\']);
+
+    root_folders.iter()
+        .find(|folder| folder.path.trim_end_matches(['/', '\\']) == path)
+        .ok_or_else(|| error::NoRootFolder::with_msg(&format!("{} is not a known root folder", path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder(id: u32, path: &str, free_gb: u64) -> RootFolderResponse {
+        RootFolderResponse {
+            free_space: free_gb * BYTES_PER_GB,
+            total_space: None,
+            accessible: Some(true),
+            unmapped_folders: vec![],
+            id,
+            path: String::from(path),
+        }
+    }
+
+    fn folders() -> Vec<RootFolderResponse> {
+        vec![
+            folder(1, "/storage/Movies/", 10),
+            folder(2, "/storage/Horror/", 500),
+            folder(3, "/storage/Kids/", 100),
+        ]
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn empty_root_folders_is_an_error() {
+        assert!(RootFolderStrategy::First.select(&[], &[], &[]).is_err());
+    }
+
+    #[test]
+    fn most_free_space_picks_largest() {
+        let folders = folders();
+        let folder = RootFolderStrategy::MostFreeSpace.select(&folders, &[], &[]).unwrap();
+
+        assert_eq!(folder.id, 2);
+    }
+
+    #[test]
+    fn minimum_free_space_picks_first_with_enough_room() {
+        let folders = folders();
+
+        assert_eq!(RootFolderStrategy::MinimumFreeSpace(50).select(&folders, &[], &[]).unwrap().id, 2);
+        assert!(RootFolderStrategy::MinimumFreeSpace(1000).select(&folders, &[], &[]).is_err());
+    }
+
+    #[test]
+    fn explicit_path_must_be_known() {
+        let folders = folders();
+
+        assert_eq!(RootFolderStrategy::Explicit(String::from("/storage/Kids")).select(&folders, &[], &[]).unwrap().id, 3);
+        assert!(RootFolderStrategy::Explicit(String::from("/nope/")).select(&folders, &[], &[]).is_err());
+    }
+
+    #[test]
+    fn rules_match_by_genre_and_tag() {
+        let folders = folders();
+        let rules: RootFolderRules = serde_json::from_value(serde_json::json!({
+            "rules": [
+                { "genre": "horror", "path": "/storage/Horror/" },
+                { "genre": "Animation", "tag": "kids", "path": "/storage/Kids/" },
+            ],
+            "default": "/storage/Movies/",
+        })).unwrap();
+        let strategy = RootFolderStrategy::Rules(rules);
+
+        let horror = strategy.select(&folders, &strings(&["Horror", "Thriller"]), &[]).unwrap();
+        let kids = strategy.select(&folders, &strings(&["Animation"]), &strings(&["kids"])).unwrap();
+        let other = strategy.select(&folders, &strings(&["Animation"]), &[]).unwrap();
+
+        assert_eq!(horror.id, 2);
+        assert_eq!(kids.id, 3);
+        assert_eq!(other.id, 1);
+    }
+
+    #[test]
+    fn rules_without_default_can_fail() {
+        let folders = folders();
+        let strategy = RootFolderStrategy::Rules(RootFolderRules::default());
+
+        assert!(strategy.select(&folders, &strings(&["Drama"]), &[]).is_err());
+    }
+}
@@ -1,10 +1,12 @@
 // pub mod radarr;
 
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 
 use crate::radarr::AlternativeTitle;
 use crate::radarr::MovieFile;
 
+use super::enums::{MovieStatus, MinimumAvailability};
 use super::image::Image;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -20,11 +22,17 @@ pub struct SearchResult {
     #[serde(rename = "alternateTitles")]
     pub alternate_titles: Vec<AlternativeTitle>,
 
-    pub status: String,
+    pub status: MovieStatus,
     pub overview: String,
 
-    #[serde(rename = "inCinemas")]
-    pub in_cinemas: Option<String>,
+    #[serde(rename = "inCinemas", default, with = "super::datetime::option")]
+    pub in_cinemas: Option<DateTime<Utc>>,
+
+    #[serde(rename = "physicalRelease", default, with = "super::datetime::option", skip_serializing_if = "Option::is_none")]
+    pub physical_release: Option<DateTime<Utc>>,
+
+    #[serde(rename = "digitalRelease", default, with = "super::datetime::option", skip_serializing_if = "Option::is_none")]
+    pub digital_release: Option<DateTime<Utc>>,
 
     pub year: u32,
 
@@ -34,7 +42,7 @@ pub struct SearchResult {
     pub movie_file: Option<MovieFile>,
 
     #[serde(rename = "minimumAvailability")]
-    pub minimum_availability: String,
+    pub minimum_availability: MinimumAvailability,
 
     #[serde(rename = "isAvailable")]
     pub is_available: bool,
@@ -51,12 +59,26 @@ pub struct SearchResult {
     pub title_slug: String,
 
     pub genres: Vec<String>,
-    pub tags: Vec<String>,
-    pub added: String,
+    // tag ids, only set for movies that are already in the library
+    #[serde(default)]
+    pub tags: Vec<u32>,
+
+    #[serde(with = "super::datetime")]
+    pub added: DateTime<Utc>,
 
     #[serde(rename = "qualityProfileId")]
     pub quality_profile_id: u32,
 
     pub images: Vec<Image>,
+
+    // only set (and non-zero) when the movie is already in the library
+    #[serde(default)]
+    pub id: Option<u32>,
+}
+
+impl SearchResult {
+    pub fn is_existing(&self) -> bool {
+        self.id.unwrap_or(0) != 0
+    }
 }
 
@@ -0,0 +1,566 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use super::client::Client;
+use super::custom_format::CustomFormat;
+use super::quality_profile::QualityProfile;
+use super::provider::Provider;
+
+// A whole instance's configuration as one versionable document. Everything that references
+// something else by id (tags, custom formats) references it by name instead so the document can
+// be applied to any instance. Sections that are left out of a document are left alone by `apply`,
+// and items are only ever created or updated, never deleted.
+//
+// tags: ["kids", "4k"]
+// rootFolders: ["/storage/Movies/"]
+// customFormats: [{ name: "x265", specifications: [...] }]     (same shape as the web UI's export)
+// qualityProfiles: [{ name: "HD-1080p", cutoff: 7, formatItems: [{ name: "x265", score: 10 }], ... }]
+// naming: { renameMovies: true, standardMovieFormat: "{Movie Title} ({Release Year}) {Quality Full}", ... }
+// mediaManagement: { copyUsingHardlinks: true, ... }
+// indexers: [{ name: "NZBGeek", implementation: "Newznab", fields: [{ name: "baseUrl", value: "..." }], tags: ["4k"] }]
+// downloadClients: [...]
+// notifications: [...]
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct InstanceSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(rename = "rootFolders", skip_serializing_if = "Option::is_none")]
+    pub root_folders: Option<Vec<String>>,
+
+    #[serde(rename = "customFormats", skip_serializing_if = "Option::is_none")]
+    pub custom_formats: Option<Vec<Value>>,
+
+    #[serde(rename = "qualityProfiles", skip_serializing_if = "Option::is_none")]
+    pub quality_profiles: Option<Vec<Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub naming: Option<Value>,
+
+    #[serde(rename = "mediaManagement", skip_serializing_if = "Option::is_none")]
+    pub media_management: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexers: Option<Vec<Value>>,
+
+    #[serde(rename = "downloadClients", skip_serializing_if = "Option::is_none")]
+    pub download_clients: Option<Vec<Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<Vec<Value>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SettingsChange {
+    pub section: String,
+    pub name: String,
+    pub action: ChangeAction,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeAction {
+    Create,
+    Update,
+    Unchanged,
+}
+
+// read-only bits of providers that the api sends but that don't belong in a document
+const PROVIDER_NOISE: [&str; 5] = ["id", "implementationName", "infoLink", "message", "presets"];
+
+impl InstanceSettings {
+    // dump every section of the instance's current configuration
+    pub fn export(client: &Client) -> Result<InstanceSettings, Box<dyn Error>> {
+        let tags = client.tags()?.data;
+        let tag_labels: HashMap<u32, String> = tags.iter().map(|tag| (tag.id, tag.label.to_owned())).collect();
+
+        let providers = |providers: Vec<Provider>| -> Vec<Value> {
+            providers.iter().map(|provider| normalize_provider(provider, &tag_labels)).collect()
+        };
+
+        Ok(InstanceSettings {
+            tags: Some(tags.iter().map(|tag| tag.label.to_owned()).collect()),
+            root_folders: Some(client.root_folder()?.data.iter().map(|folder| folder.path.to_owned()).collect()),
+            custom_formats: Some(client.custom_formats()?.data.iter().map(CustomFormat::export).collect()),
+            quality_profiles: Some(client.quality_profiles()?.data.iter().map(normalize_quality_profile).collect()),
+            naming: Some(normalize_singleton(&serde_json::to_value(&client.naming_config()?.data)?)),
+            media_management: Some(normalize_singleton(&serde_json::to_value(&client.media_management_config()?.data)?)),
+            indexers: Some(providers(*client.indexers()?.data)),
+            download_clients: Some(providers(*client.download_clients()?.data)),
+            notifications: Some(providers(*client.notifications()?.data)),
+        })
+    }
+
+    // bring the instance in line with this document, returning what changed. With `dry_run` nothing
+    // is written and the changes are what would have happened.
+    pub fn apply(&self, client: &Client, dry_run: bool) -> Result<Vec<SettingsChange>, Box<dyn Error>> {
+        let mut changes = vec![];
+
+        // tags first, since providers reference them
+        let mut tag_ids: HashMap<String, u32> = client.tags()?.data.iter()
+            .map(|tag| (tag.label.to_owned(), tag.id))
+            .collect();
+
+        for label in self.tags.iter().flatten() {
+            let action = if tag_ids.contains_key(label) { ChangeAction::Unchanged } else { ChangeAction::Create };
+
+            if action == ChangeAction::Create && !dry_run {
+                let tag = client.create_tag(label)?;
+                tag_ids.insert(tag.data.label.to_owned(), tag.data.id);
+            }
+
+            changes.push(SettingsChange::new("tags", label, action));
+        }
+        let tag_labels: HashMap<u32, String> = tag_ids.iter().map(|(label, id)| (*id, label.to_owned())).collect();
+
+        if let Some(root_folders) = &self.root_folders {
+            let current: Vec<String> = client.root_folder()?.data.iter()
+                .map(|folder| folder.path.trim_end_matches(['/', '\\']).to_owned())
+                .collect();
+
+            for path in root_folders {
+                let exists = current.iter().any(|current| current == path.trim_end_matches(['/', '\\']));
+                let action = if exists { ChangeAction::Unchanged } else { ChangeAction::Create };
+
+                if action == ChangeAction::Create && !dry_run {
+                    client.add_root_folder(path)?;
+                }
+
+                changes.push(SettingsChange::new("rootFolders", path, action));
+            }
+        }
+
+        // custom formats before quality profiles, which score them
+        let mut custom_formats = *client.custom_formats()?.data;
+
+        if let Some(desired) = &self.custom_formats {
+            let current: Vec<Value> = custom_formats.iter().map(CustomFormat::export).collect();
+            let ids: HashMap<String, Option<u32>> = custom_formats.iter().map(|cf| (cf.name.to_owned(), cf.id)).collect();
+
+            apply_section(&mut changes, "customFormats", &current, desired, dry_run, |existing, value| {
+                let mut custom_format: CustomFormat = serde_json::from_value(value)?;
+
+                if existing.is_some() {
+                    custom_format.id = ids[&custom_format.name];
+                    client.update_custom_format(&custom_format)?;
+                } else {
+                    custom_format.id = None;
+                    client.create_custom_format(&custom_format)?;
+                }
+
+                Ok(())
+            })?;
+
+            if !dry_run {
+                custom_formats = *client.custom_formats()?.data;
+            }
+        }
+
+        if let Some(desired) = &self.quality_profiles {
+            let profiles = client.quality_profiles()?.data;
+            let current: Vec<Value> = profiles.iter().map(normalize_quality_profile).collect();
+            let ids: HashMap<String, Option<u32>> = profiles.iter().map(|profile| (profile.name.to_owned(), profile.id)).collect();
+
+            apply_section(&mut changes, "qualityProfiles", &current, desired, dry_run, |existing, value| {
+                let mut profile = denormalize_quality_profile(&value, &custom_formats)?;
+
+                if existing.is_some() {
+                    profile.id = ids[&profile.name];
+                    client.update_quality_profile(&profile)?;
+                } else {
+                    profile.id = None;
+                    client.create_quality_profile(&profile)?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if let Some(desired) = &self.naming {
+            let current = serde_json::to_value(&client.naming_config()?.data)?;
+
+            apply_singleton(&mut changes, "naming", &current, desired, dry_run, |value| {
+                client.update_naming_config(&serde_json::from_value(value)?).map(|_| ())
+            })?;
+        }
+
+        if let Some(desired) = &self.media_management {
+            let current = serde_json::to_value(&client.media_management_config()?.data)?;
+
+            apply_singleton(&mut changes, "mediaManagement", &current, desired, dry_run, |value| {
+                client.update_media_management_config(&serde_json::from_value(value)?).map(|_| ())
+            })?;
+        }
+
+        if let Some(desired) = &self.indexers {
+            let current = client.indexers()?.data;
+
+            apply_providers(&mut changes, "indexers", &current, desired, &tag_labels, dry_run, |provider| {
+                match provider.id {
+                    Some(_) => client.update_indexer(provider).map(|_| ()),
+                    None => client.create_indexer(provider).map(|_| ()),
+                }
+            })?;
+        }
+
+        if let Some(desired) = &self.download_clients {
+            let current = client.download_clients()?.data;
+
+            apply_providers(&mut changes, "downloadClients", &current, desired, &tag_labels, dry_run, |provider| {
+                match provider.id {
+                    Some(_) => client.update_download_client(provider).map(|_| ()),
+                    None => client.create_download_client(provider).map(|_| ()),
+                }
+            })?;
+        }
+
+        if let Some(desired) = &self.notifications {
+            let current = client.notifications()?.data;
+
+            apply_providers(&mut changes, "notifications", &current, desired, &tag_labels, dry_run, |provider| {
+                match provider.id {
+                    Some(_) => client.update_notification(provider).map(|_| ()),
+                    None => client.create_notification(provider).map(|_| ()),
+                }
+            })?;
+        }
+
+        Ok(changes)
+    }
+}
+
+impl SettingsChange {
+    fn new(section: &str, name: &str, action: ChangeAction) -> SettingsChange {
+        SettingsChange {
+            section: String::from(section),
+            name: String::from(name),
+            action,
+        }
+    }
+}
+
+// match each desired item to a current one by name and hand anything that needs writing to
+// `write`, along with the current item (if any) and the value to write. Updates are the current
+// item with the desired one merged over it, so documents only need to list what they care about.
+fn apply_section<F>(changes: &mut Vec<SettingsChange>, section: &str, current: &[Value], desired: &[Value], dry_run: bool, mut write: F) -> Result<(), Box<dyn Error>>
+    where F: FnMut(Option<&Value>, Value) -> Result<(), Box<dyn Error>>
+{
+    for item in desired {
+        let name = item["name"].as_str()
+            .ok_or_else(|| format!("every item in {} needs a name", section))?;
+        let existing = current.iter().find(|current| current["name"].as_str() == Some(name));
+
+        let (action, value) = match existing {
+            Some(existing) if is_subset(item, existing) => (ChangeAction::Unchanged, None),
+            Some(existing) => {
+                let mut merged = existing.clone();
+                merge(&mut merged, item);
+
+                (ChangeAction::Update, Some(merged))
+            },
+            None => (ChangeAction::Create, Some(item.clone())),
+        };
+
+        if let (Some(value), false) = (value, dry_run) {
+            write(existing, value)?;
+        }
+
+        changes.push(SettingsChange::new(section, name, action));
+    }
+
+    Ok(())
+}
+
+fn apply_singleton<F>(changes: &mut Vec<SettingsChange>, section: &str, current: &Value, desired: &Value, dry_run: bool, write: F) -> Result<(), Box<dyn Error>>
+    where F: FnOnce(Value) -> Result<(), Box<dyn Error>>
+{
+    let action = if is_subset(desired, &normalize_singleton(current)) {
+        ChangeAction::Unchanged
+    } else {
+        ChangeAction::Update
+    };
+
+    if action == ChangeAction::Update && !dry_run {
+        // keep the current id in there
+        let mut merged = current.clone();
+        merge(&mut merged, desired);
+
+        write(merged)?;
+    }
+
+    changes.push(SettingsChange::new(section, section, action));
+
+    Ok(())
+}
+
+fn apply_providers<F>(changes: &mut Vec<SettingsChange>, section: &str, current: &[Provider], desired: &[Value], tag_labels: &HashMap<u32, String>, dry_run: bool, write: F) -> Result<(), Box<dyn Error>>
+    where F: Fn(&Provider) -> Result<(), Box<dyn Error>>
+{
+    let normalized: Vec<Value> = current.iter().map(|provider| normalize_provider(provider, tag_labels)).collect();
+
+    apply_section(changes, section, &normalized, desired, dry_run, |existing, value| {
+        let mut provider = denormalize_provider(&value, tag_labels)?;
+
+        provider.id = match existing {
+            Some(_) => current.iter().find(|current| current.name == provider.name).and_then(|current| current.id),
+            None => None,
+        };
+
+        write(&provider)
+    })
+}
+
+fn normalize_singleton(value: &Value) -> Value {
+    let mut value = value.clone();
+
+    if let Some(object) = value.as_object_mut() {
+        object.remove("id");
+    }
+
+    value
+}
+
+// drop ids and ui-only metadata, and refer to tags by label
+fn normalize_provider(provider: &Provider, tag_labels: &HashMap<u32, String>) -> Value {
+    let mut value = serde_json::to_value(provider).unwrap_or(Value::Null);
+
+    if let Some(object) = value.as_object_mut() {
+        for key in PROVIDER_NOISE.iter() {
+            object.remove(*key);
+        }
+
+        let tags: Vec<Value> = provider.tags.iter()
+            .map(|id| tag_labels.get(id).map(|label| Value::from(label.as_str())).unwrap_or_else(|| Value::from(*id)))
+            .collect();
+        object.insert(String::from("tags"), Value::Array(tags));
+
+        let fields: Vec<Value> = provider.fields.iter()
+            .map(|field| {
+                let mut map = Map::new();
+                map.insert(String::from("name"), Value::from(field.name.as_str()));
+                map.insert(String::from("value"), field.value.clone());
+                Value::Object(map)
+            })
+            .collect();
+        object.insert(String::from("fields"), Value::Array(fields));
+    }
+
+    value
+}
+
+fn denormalize_provider(value: &Value, tag_labels: &HashMap<u32, String>) -> Result<Provider, Box<dyn Error>> {
+    let mut value = value.clone();
+
+    if let Some(tags) = value.get_mut("tags").and_then(Value::as_array_mut) {
+        for tag in tags.iter_mut() {
+            if let Some(label) = tag.as_str() {
+                let id = tag_labels.iter()
+                    .find(|(_, current)| current.as_str() == label)
+                    .map(|(id, _)| *id)
+                    .ok_or_else(|| format!("unknown tag: {}", label))?;
+
+                *tag = Value::from(id);
+            }
+        }
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+// drop the id and refer to custom formats by name only
+fn normalize_quality_profile(profile: &QualityProfile) -> Value {
+    let mut value = serde_json::to_value(profile).unwrap_or(Value::Null);
+
+    if let Some(object) = value.as_object_mut() {
+        object.remove("id");
+    }
+
+    if let Some(items) = value.get_mut("formatItems").and_then(Value::as_array_mut) {
+        for item in items.iter_mut().filter_map(Value::as_object_mut) {
+            item.remove("format");
+        }
+    }
+
+    value
+}
+
+// resolve custom formats by name. Radarr wants every custom format scored in every profile, so
+// any the document doesn't mention get a score of 0.
+fn denormalize_quality_profile(value: &Value, custom_formats: &[CustomFormat]) -> Result<QualityProfile, Box<dyn Error>> {
+    let mut value = value.clone();
+    let mut items = match value.get("formatItems").and_then(Value::as_array) {
+        Some(items) => items.clone(),
+        None => vec![],
+    };
+
+    for item in items.iter_mut() {
+        let name = item["name"].as_str().unwrap_or_default().to_owned();
+        let custom_format = custom_formats.iter()
+            .find(|custom_format| custom_format.name == name)
+            .ok_or_else(|| format!("unknown custom format: {}", name))?;
+
+        item["format"] = Value::from(custom_format.id);
+    }
+
+    for custom_format in custom_formats {
+        if !items.iter().any(|item| item["name"].as_str() == Some(custom_format.name.as_str())) {
+            items.push(serde_json::json!({ "format": custom_format.id, "name": custom_format.name, "score": 0 }));
+        }
+    }
+
+    if let Some(object) = value.as_object_mut() {
+        object.insert(String::from("formatItems"), Value::Array(items));
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+// true when everything in `desired` is also in `current`. Objects may have extra keys in `current`
+// but arrays need the same items: matched up by `name` when every item has one (eg: `formatItems`
+// and provider `fields`, which the server orders however it likes), otherwise item for item.
+fn is_subset(desired: &Value, current: &Value) -> bool {
+    match (desired, current) {
+        (Value::Object(desired), Value::Object(current)) => {
+            desired.iter().all(|(key, value)| current.get(key).is_some_and(|current| is_subset(value, current)))
+        },
+        (Value::Array(desired), Value::Array(current)) if is_keyed_by_name(desired) && is_keyed_by_name(current) => {
+            desired.len() == current.len() && desired.iter().all(|d| {
+                current.iter().any(|c| c["name"] == d["name"] && is_subset(d, c))
+            })
+        },
+        (Value::Array(desired), Value::Array(current)) => {
+            desired.len() == current.len() && desired.iter().zip(current.iter()).all(|(d, c)| is_subset(d, c))
+        },
+        (Value::Number(desired), Value::Number(current)) => desired.as_f64() == current.as_f64(),
+        (desired, current) => desired == current,
+    }
+}
+
+fn is_keyed_by_name(items: &[Value]) -> bool {
+    !items.is_empty() && items.iter().all(|item| item.get("name").is_some_and(Value::is_string))
+}
+
+// deep merge `desired` over `current`. Arrays are replaced rather than merged.
+fn merge(current: &mut Value, desired: &Value) {
+    match (current, desired) {
+        (Value::Object(current), Value::Object(desired)) => {
+            for (key, value) in desired {
+                merge(current.entry(key.to_owned()).or_insert(Value::Null), value);
+            }
+        },
+        (current, desired) => *current = desired.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn subset_allows_extra_keys_but_not_extra_items() {
+        let current = json!({ "name": "a", "enable": true, "fields": [{ "name": "x", "value": 1 }] });
+
+        assert!(is_subset(&json!({ "name": "a" }), &current));
+        assert!(is_subset(&json!({ "fields": [{ "value": 1.0 }] }), &current));
+        assert!(!is_subset(&json!({ "enable": false }), &current));
+        assert!(!is_subset(&json!({ "fields": [] }), &current));
+        assert!(!is_subset(&json!({ "missing": 1 }), &current));
+    }
+
+    #[test]
+    fn subset_matches_named_items_in_any_order() {
+        let current = json!({ "formatItems": [{ "name": "x265", "score": 10 }, { "name": "HDR", "score": 5 }] });
+
+        assert!(is_subset(&json!({ "formatItems": [{ "name": "HDR", "score": 5 }, { "name": "x265", "score": 10 }] }), &current));
+        assert!(!is_subset(&json!({ "formatItems": [{ "name": "HDR", "score": 10 }, { "name": "x265", "score": 5 }] }), &current));
+        assert!(!is_subset(&json!({ "list": [2, 1] }), &json!({ "list": [1, 2] })));
+    }
+
+    #[test]
+    fn merge_overlays_objects_and_replaces_arrays() {
+        let mut current = json!({ "id": 1, "a": { "b": 1, "c": 2 }, "list": [1, 2] });
+        merge(&mut current, &json!({ "a": { "c": 3 }, "list": [3] }));
+
+        assert_eq!(current, json!({ "id": 1, "a": { "b": 1, "c": 3 }, "list": [3] }));
+    }
+
+    #[test]
+    fn providers_round_trip_through_tag_labels() {
+        let provider: Provider = serde_json::from_value(json!({
+            "id": 2,
+            "name": "NZBGeek",
+            "implementation": "Newznab",
+            "implementationName": "Newznab",
+            "enableRss": true,
+            "fields": [{ "order": 0, "name": "baseUrl", "label": "URL", "value": "https://api.nzbgeek.info" }],
+            "tags": [1],
+        })).unwrap();
+        let tag_labels: HashMap<u32, String> = vec![(1, String::from("4k"))].into_iter().collect();
+
+        let normalized = normalize_provider(&provider, &tag_labels);
+        assert_eq!(normalized, json!({
+            "name": "NZBGeek",
+            "implementation": "Newznab",
+            "enableRss": true,
+            "fields": [{ "name": "baseUrl", "value": "https://api.nzbgeek.info" }],
+            "tags": ["4k"],
+        }));
+
+        let denormalized = denormalize_provider(&normalized, &tag_labels).unwrap();
+        assert_eq!(denormalized.tags, vec![1]);
+        assert!(denormalize_provider(&json!({ "name": "x", "implementation": "y", "tags": ["nope"] }), &tag_labels).is_err());
+    }
+
+    #[test]
+    fn quality_profiles_resolve_custom_formats_by_name() {
+        let custom_formats: Vec<CustomFormat> = serde_json::from_value(json!([
+            { "id": 3, "name": "x265", "specifications": [] },
+            { "id": 4, "name": "HDR", "specifications": [] },
+        ])).unwrap();
+        let desired = json!({ "name": "HD", "formatItems": [{ "name": "x265", "score": 10 }] });
+
+        let profile = denormalize_quality_profile(&desired, &custom_formats).unwrap();
+        assert_eq!(profile.settings["formatItems"], json!([
+            { "format": 3, "name": "x265", "score": 10 },
+            { "format": 4, "name": "HDR", "score": 0 },
+        ]));
+
+        let normalized = normalize_quality_profile(&profile);
+        assert_eq!(normalized["formatItems"][0], json!({ "name": "x265", "score": 10 }));
+
+        let unknown = json!({ "name": "HD", "formatItems": [{ "name": "nope", "score": 1 }] });
+        assert!(denormalize_quality_profile(&unknown, &custom_formats).is_err());
+    }
+
+    #[test]
+    fn apply_section_plans_creates_and_updates() {
+        let current = vec![json!({ "name": "a", "value": 1 }), json!({ "name": "b", "value": 2 })];
+        let desired = vec![json!({ "name": "a", "value": 1 }), json!({ "name": "b", "value": 3 }), json!({ "name": "c" })];
+        let mut changes = vec![];
+        let mut written = vec![];
+
+        apply_section(&mut changes, "things", &current, &desired, false, |existing, value| {
+            written.push((existing.is_some(), value));
+            Ok(())
+        }).unwrap();
+
+        let actions: Vec<ChangeAction> = changes.iter().map(|change| change.action).collect();
+        assert_eq!(actions, vec![ChangeAction::Unchanged, ChangeAction::Update, ChangeAction::Create]);
+        assert_eq!(written, vec![(true, json!({ "name": "b", "value": 3 })), (false, json!({ "name": "c" }))]);
+    }
+
+    #[test]
+    fn apply_section_dry_run_writes_nothing() {
+        let mut changes = vec![];
+
+        apply_section(&mut changes, "things", &[], &[json!({ "name": "c" })], true, |_, _| {
+            panic!("dry runs shouldn't write");
+        }).unwrap();
+
+        assert_eq!(changes, vec![SettingsChange::new("things", "c", ChangeAction::Create)]);
+    }
+}
@@ -0,0 +1,219 @@
+use serde::{Serialize, Deserialize};
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs;
+
+use super::title_match::normalize_title;
+
+// Where films showing at cinemas come from, so they can be matched against Radarr and added.
+
+pub trait ShowtimeSource {
+    fn showings(&self) -> Result<Vec<Showing>, Box<dyn Error>>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Showing {
+    pub cinema: String,
+    pub title: String,
+    pub year: Option<u32>,
+
+    // the series or program it's part of, eg: "Terror Tuesday"
+    pub show_type: Option<String>,
+
+    // set when the feed knows it, which saves matching on the title
+    pub tmdb_id: Option<u32>,
+}
+
+// A json document of cinemas and what's showing at them, read from a file or fetched from a url:
+//
+// {
+//   "cinemas": [
+//     {
+//       "name": "Ritz",
+//       "films": [
+//         { "title": "Alien", "year": 1979, "showType": "Terror Tuesday", "tmdbId": 348 },
+//         { "title": "Dune: Part Two", "showType": "First Run" }
+//       ]
+//     }
+//   ]
+// }
+pub struct CinemaFeed {
+    location: String,
+}
+
+#[derive(Deserialize)]
+struct Feed {
+    #[serde(default)]
+    cinemas: Vec<FeedCinema>,
+}
+
+#[derive(Deserialize)]
+struct FeedCinema {
+    name: String,
+
+    #[serde(default)]
+    films: Vec<FeedFilm>,
+}
+
+#[derive(Deserialize)]
+struct FeedFilm {
+    title: String,
+    year: Option<u32>,
+
+    #[serde(rename = "showType")]
+    show_type: Option<String>,
+
+    #[serde(rename = "tmdbId")]
+    tmdb_id: Option<u32>,
+}
+
+impl CinemaFeed {
+    // `location` is a url if it starts with http:// or https://, otherwise a file path
+    pub fn new(location: &str) -> CinemaFeed {
+        CinemaFeed {
+            location: String::from(location),
+        }
+    }
+
+    fn read(&self) -> Result<String, Box<dyn Error>> {
+        if self.location.starts_with("http://") || self.location.starts_with("https://") {
+            let mut resp = reqwest::get(&self.location)?;
+
+            if !resp.status().is_success() {
+                return Err(format!("Fetching {} failed with {}", self.location, resp.status()).into());
+            }
+
+            Ok(resp.text()?)
+        } else {
+            Ok(fs::read_to_string(&self.location)?)
+        }
+    }
+}
+
+impl ShowtimeSource for CinemaFeed {
+    fn showings(&self) -> Result<Vec<Showing>, Box<dyn Error>> {
+        parse_feed(&self.read()?)
+    }
+}
+
+fn parse_feed(data: &str) -> Result<Vec<Showing>, Box<dyn Error>> {
+    let feed: Feed = serde_json::from_str(data)?;
+
+    let showings = feed.cinemas.into_iter()
+        .flat_map(|cinema| {
+            let name = cinema.name;
+
+            cinema.films.into_iter().map(move |film| Showing {
+                cinema: name.to_owned(),
+                title: film.title,
+                year: film.year,
+                show_type: film.show_type,
+                tmdb_id: film.tmdb_id,
+            })
+        })
+        .collect();
+
+    Ok(showings)
+}
+
+// Which showings to keep. Empty lists keep everything; names are compared ignoring case and
+// punctuation, so "terror tuesday" matches "Terror Tuesday!".
+#[derive(Debug, Default, Clone)]
+pub struct ShowingFilter {
+    pub show_types: Vec<String>,
+    pub cinemas: Vec<String>,
+}
+
+impl ShowingFilter {
+    pub fn matches(&self, showing: &Showing) -> bool {
+        matches_any(&self.show_types, showing.show_type.as_deref()) && matches_any(&self.cinemas, Some(&showing.cinema))
+    }
+
+    // the showings that pass the filter, with films showing more than once listed just once
+    pub fn apply(&self, showings: Vec<Showing>) -> Vec<Showing> {
+        let mut seen = HashSet::new();
+
+        showings.into_iter()
+            .filter(|showing| self.matches(showing))
+            .filter(|showing| seen.insert((normalize_title(&showing.title), showing.year)))
+            .collect()
+    }
+}
+
+fn matches_any(wanted: &[String], value: Option<&str>) -> bool {
+    if wanted.is_empty() {
+        return true;
+    }
+
+    match value {
+        Some(value) => {
+            let value = normalize_title(value);
+            wanted.iter().any(|wanted| normalize_title(wanted) == value)
+        },
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"{
+        "cinemas": [
+            {
+                "name": "Ritz",
+                "films": [
+                    { "title": "Alien", "year": 1979, "showType": "Terror Tuesday", "tmdbId": 348 },
+                    { "title": "Dune: Part Two", "showType": "First Run" },
+                    { "title": "Miami Connection", "year": 1987, "showType": "Weird Wednesday" }
+                ]
+            },
+            {
+                "name": "Village",
+                "films": [
+                    { "title": "Alien", "year": 1979, "showType": "Terror Tuesday" },
+                    { "title": "Tammy and the T-Rex", "year": 1994, "showType": "Video Vortex" },
+                    { "title": "Paddington 2" }
+                ]
+            }
+        ]
+    }"#;
+
+    fn titles(showings: &[Showing]) -> Vec<&str> {
+        showings.iter().map(|showing| showing.title.as_str()).collect()
+    }
+
+    #[test]
+    fn parse_feed_flattens_cinemas() {
+        let showings = parse_feed(FEED).unwrap();
+
+        assert_eq!(showings.len(), 6);
+        assert_eq!(showings[0], Showing {
+            cinema: String::from("Ritz"),
+            title: String::from("Alien"),
+            year: Some(1979),
+            show_type: Some(String::from("Terror Tuesday")),
+            tmdb_id: Some(348),
+        });
+    }
+
+    #[test]
+    fn filter_keeps_wanted_show_types_once() {
+        let filter = ShowingFilter {
+            show_types: vec![String::from("terror tuesday"), String::from("Weird Wednesday"), String::from("Video Vortex")],
+            cinemas: vec![],
+        };
+
+        let showings = filter.apply(parse_feed(FEED).unwrap());
+        assert_eq!(titles(&showings), vec!["Alien", "Miami Connection", "Tammy and the T-Rex"]);
+    }
+
+    #[test]
+    fn filter_by_cinema_and_empty_filter() {
+        let filter = ShowingFilter { show_types: vec![], cinemas: vec![String::from("village")] };
+        assert_eq!(titles(&filter.apply(parse_feed(FEED).unwrap())), vec!["Alien", "Tammy and the T-Rex", "Paddington 2"]);
+
+        assert_eq!(ShowingFilter::default().apply(parse_feed(FEED).unwrap()).len(), 5);
+    }
+}
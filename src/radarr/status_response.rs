@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 
 // appData: "/config"
@@ -28,8 +29,8 @@ pub struct StatusResponse {
     pub authentication: String,
     pub branch: String,
 
-    #[serde(rename = "buildTime")]
-    pub build_time: String,
+    #[serde(rename = "buildTime", with = "super::datetime")]
+    pub build_time: DateTime<Utc>,
 
     #[serde(rename = "isAdmin")]
     pub is_admin: bool,
@@ -52,6 +53,10 @@ pub struct StatusResponse {
     #[serde(rename = "isWindows")]
     pub is_windows: bool,
 
+    // only sent by newer versions. Changes whenever Radarr restarts.
+    #[serde(rename = "startTime", default, with = "super::datetime::option", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Utc>>,
+
     #[serde(rename = "runtimeVersion")]
     pub runtime_version: String,
 
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+// name: "RssSync"
+// taskName: "RssSync"
+// interval: 15
+// lastExecution: "2021-06-27T03:45:00Z"
+// lastStartTime: "2021-06-27T03:45:00Z"
+// nextExecution: "2021-06-27T04:00:00Z"
+// lastDuration: "00:00:03.1234567"
+// id: 4
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SystemTask {
+    pub name: String,
+
+    #[serde(rename = "taskName")]
+    pub task_name: String,
+
+    // minutes
+    pub interval: u32,
+
+    #[serde(rename = "lastExecution", default, with = "super::datetime::option")]
+    pub last_execution: Option<DateTime<Utc>>,
+
+    #[serde(rename = "lastStartTime", default, with = "super::datetime::option")]
+    pub last_start_time: Option<DateTime<Utc>>,
+
+    #[serde(rename = "nextExecution", default, with = "super::datetime::option")]
+    pub next_execution: Option<DateTime<Utc>>,
+
+    #[serde(rename = "lastDuration")]
+    pub last_duration: Option<String>,
+
+    pub id: u32,
+}
@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+
+// id: 1
+// label: "kids"
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Tag {
+    pub id: u32,
+    pub label: String,
+}
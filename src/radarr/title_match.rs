@@ -0,0 +1,408 @@
+use super::search_result::SearchResult;
+
+// Picks the search result that best matches a title (and optionally a year) someone typed or got
+// from a listing. Titles are compared after normalizing away the things listings disagree on:
+// case, accents, punctuation, leading articles and roman vs arabic sequel numbers. Every title a
+// result is known by counts, so "Das Boot" finds the film whichever title Radarr lists first.
+
+// how much a match on an alternate title is worth compared to the main title
+const ALTERNATE_TITLE_WEIGHT: f64 = 0.95;
+
+// how much a year that's off by no more than the tolerance is worth, and one that's off by more
+const NEAR_YEAR_WEIGHT: f64 = 0.9;
+const WRONG_YEAR_WEIGHT: f64 = 0.5;
+
+// two candidates this close together can't be told apart
+const AMBIGUITY_MARGIN: f64 = 0.05;
+
+#[derive(Debug, Clone)]
+pub struct TitleQuery {
+    pub title: String,
+    pub year: Option<u32>,
+    pub year_tolerance: u32,
+}
+
+#[derive(Debug)]
+pub struct MatchCandidate<'a> {
+    pub result: &'a SearchResult,
+
+    // the title of the result that matched best, which may be an alternate one
+    pub matched_title: &'a str,
+
+    // from 0 to 1
+    pub confidence: f64,
+}
+
+#[derive(Debug)]
+pub enum MatchOutcome<'a, 'b> {
+    Matched(&'b MatchCandidate<'a>),
+    Ambiguous(&'b [MatchCandidate<'a>]),
+    NotFound,
+}
+
+// what `TitleQuery::pick` decided, owning the search results it was given
+#[derive(Debug)]
+pub enum TitlePick {
+    // the result and the confidence it matched with
+    Matched(Box<SearchResult>, f64),
+
+    // every result with its confidence, best first
+    Ambiguous(Vec<(SearchResult, f64)>),
+
+    NotFound,
+}
+
+impl TitleQuery {
+    pub fn new(title: &str, year: Option<u32>) -> TitleQuery {
+        TitleQuery {
+            title: String::from(title),
+            year,
+            year_tolerance: 1,
+        }
+    }
+
+    pub fn with_year_tolerance(mut self, year_tolerance: u32) -> TitleQuery {
+        self.year_tolerance = year_tolerance;
+        self
+    }
+
+    // score every result, best first
+    pub fn rank<'a>(&self, results: &'a [SearchResult]) -> Vec<MatchCandidate<'a>> {
+        let mut candidates: Vec<MatchCandidate> = results.iter()
+            .map(|result| self.score(result))
+            .collect();
+
+        candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        candidates
+    }
+
+    // rank the results and take the best one out of them, refusing to guess when no result is a
+    // confident, unambiguous match
+    pub fn pick(&self, results: Vec<SearchResult>, threshold: f64) -> TitlePick {
+        let mut scored: Vec<(SearchResult, f64)> = results.into_iter()
+            .map(|result| {
+                let confidence = self.score(&result).confidence;
+                (result, confidence)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let best = match scored.first() {
+            Some((_, best)) => *best,
+            None => return TitlePick::NotFound,
+        };
+        let runner_up = scored.get(1).map(|(_, confidence)| *confidence).unwrap_or(0.0);
+
+        if is_confident(best, runner_up, threshold) {
+            let (result, confidence) = scored.swap_remove(0);
+            TitlePick::Matched(Box::new(result), confidence)
+        } else {
+            TitlePick::Ambiguous(scored)
+        }
+    }
+
+    pub fn score<'a>(&self, result: &'a SearchResult) -> MatchCandidate<'a> {
+        let query = normalize_title(&self.title);
+
+        let titles = std::iter::once((result.title.as_str(), 1.0))
+            .chain(result.alternate_titles.iter().map(|alt| (alt.title.as_str(), ALTERNATE_TITLE_WEIGHT)));
+
+        let (matched_title, title_score) = titles
+            .map(|(title, weight)| (title, similarity(&query, &normalize_title(title)) * weight))
+            .fold(("", 0.0), |best, current| if current.1 > best.1 { current } else { best });
+
+        MatchCandidate {
+            result,
+            matched_title,
+            confidence: title_score * self.year_weight(result.year),
+        }
+    }
+
+    fn year_weight(&self, year: u32) -> f64 {
+        let wanted = match self.year {
+            Some(wanted) => wanted,
+            None => return 1.0,
+        };
+
+        let off_by = wanted.abs_diff(year);
+
+        if off_by == 0 {
+            1.0
+        } else if off_by <= self.year_tolerance {
+            NEAR_YEAR_WEIGHT
+        } else {
+            WRONG_YEAR_WEIGHT
+        }
+    }
+}
+
+// decide on ranked candidates: the best one if it clears `threshold` and nothing else is as good
+pub fn best_match<'a, 'b>(candidates: &'b [MatchCandidate<'a>], threshold: f64) -> MatchOutcome<'a, 'b> {
+    let best = match candidates.first() {
+        Some(best) => best,
+        None => return MatchOutcome::NotFound,
+    };
+
+    let runner_up = candidates.get(1).map(|candidate| candidate.confidence).unwrap_or(0.0);
+
+    if is_confident(best.confidence, runner_up, threshold) {
+        MatchOutcome::Matched(best)
+    } else {
+        MatchOutcome::Ambiguous(candidates)
+    }
+}
+
+fn is_confident(best: f64, runner_up: f64, threshold: f64) -> bool {
+    best >= threshold && best - runner_up >= AMBIGUITY_MARGIN
+}
+
+// lowercase, unaccented words with punctuation, leading/trailing articles and roman numerals dealt
+// with, eg: "The Godfather: Part II" => "godfather part 2"
+pub fn normalize_title(title: &str) -> String {
+    let mut folded = String::with_capacity(title.len());
+
+    for c in title.chars().flat_map(char::to_lowercase) {
+        match c {
+            '&' => folded.push_str(" and "),
+            // apostrophes join words rather than split them: "Schindler's" => "schindlers"
+            '\'' | '\u{2019}' => {},
+            c if c.is_alphanumeric() => match fold_diacritic(c) {
+                Some(replacement) => folded.push_str(replacement),
+                None => folded.push(c),
+            },
+            _ => folded.push(' '),
+        }
+    }
+
+    let mut words: Vec<String> = folded.split_whitespace()
+        .map(|word| roman_to_arabic(word).map(|n| n.to_string()).unwrap_or_else(|| word.to_string()))
+        .collect();
+
+    // "The Matrix" and "Matrix, The" are the same film, but a title that's only an article isn't
+    if words.len() > 1 && is_article(&words[0]) {
+        words.remove(0);
+    }
+
+    if words.len() > 1 && is_article(&words[words.len() - 1]) {
+        words.pop();
+    }
+
+    words.join(" ")
+}
+
+fn is_article(word: &str) -> bool {
+    matches!(word, "the" | "a" | "an")
+}
+
+fn fold_diacritic(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' | 'ĺ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' | 'ŕ' => "r",
+        'ß' => "ss",
+        'ś' | 'š' | 'ş' => "s",
+        'ť' | 'ţ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'þ' => "th",
+        _ => return None,
+    };
+
+    Some(folded)
+}
+
+// sequel numbers only: "ii" through "xxxix". A lone "i" is far more often the word than the number.
+fn roman_to_arabic(word: &str) -> Option<u32> {
+    if word == "i" || word.is_empty() || !word.chars().all(|c| matches!(c, 'i' | 'v' | 'x')) {
+        return None;
+    }
+
+    let value = |c| match c {
+        'i' => 1,
+        'v' => 5,
+        _ => 10,
+    };
+
+    let digits: Vec<u32> = word.chars().map(value).collect();
+    let mut total = 0;
+
+    for (i, digit) in digits.iter().enumerate() {
+        match digits.get(i + 1) {
+            Some(next) if next > digit => total -= *digit as i32,
+            _ => total += *digit as i32,
+        }
+    }
+
+    // reject things that only look like numerals, like "iiiii" or "vx", by round tripping
+    let total = total as u32;
+    if total < 40 && to_roman(total) == word { Some(total) } else { None }
+}
+
+fn to_roman(mut n: u32) -> String {
+    const NUMERALS: [(u32, &str); 5] = [(10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i")];
+
+    let mut roman = String::new();
+
+    for (value, numeral) in NUMERALS.iter() {
+        while n >= *value {
+            roman.push_str(numeral);
+            n -= value;
+        }
+    }
+
+    roman
+}
+
+// 1 for identical strings down to 0 for nothing in common, based on edit distance
+fn similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+
+    if longest == 0 {
+        return 0.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == b_char { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    1.0 - previous[b.len()] as f64 / longest as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(title: &str, year: u32, alternate_titles: &[&str]) -> SearchResult {
+        let alternate_titles: Vec<_> = alternate_titles.iter().map(|title| serde_json::json!({ "title": title })).collect();
+
+        serde_json::from_value(serde_json::json!({
+            "title": title,
+            "secondaryYearSourceId": 0,
+            "sortTitle": title.to_lowercase(),
+            "alternateTitles": alternate_titles,
+            "status": "released",
+            "overview": "",
+            "year": year,
+            "monitored": false,
+            "minimumAvailability": "released",
+            "isAvailable": true,
+            "folderName": "",
+            "runtime": 0,
+            "tmdbId": year,
+            "titleSlug": "",
+            "genres": [],
+            "tags": [],
+            "added": "0001-01-01T00:00:00Z",
+            "qualityProfileId": 0,
+            "images": [],
+        })).unwrap()
+    }
+
+    #[test]
+    fn normalize_title_smooths_over_listing_differences() {
+        assert_eq!(normalize_title("The Godfather: Part II"), "godfather part 2");
+        assert_eq!(normalize_title("Godfather Part 2, The"), "godfather part 2");
+        assert_eq!(normalize_title("Amélie"), "amelie");
+        assert_eq!(normalize_title("Schindler's List"), "schindlers list");
+        assert_eq!(normalize_title("Fast & Furious"), "fast and furious");
+        assert_eq!(normalize_title("I, Robot"), "i robot");
+        assert_eq!(normalize_title("Mix"), "mix");
+        assert_eq!(normalize_title("The"), "the");
+    }
+
+    #[test]
+    fn roman_numerals_are_only_converted_when_well_formed() {
+        assert_eq!(roman_to_arabic("ii"), Some(2));
+        assert_eq!(roman_to_arabic("xiv"), Some(14));
+        assert_eq!(roman_to_arabic("iiii"), None);
+        assert_eq!(roman_to_arabic("vx"), None);
+        assert_eq!(roman_to_arabic("i"), None);
+    }
+
+    #[test]
+    fn rank_uses_alternate_titles_and_year() {
+        let results = vec![
+            result("Alien", 2019, &[]),
+            result("Alien", 1979, &[]),
+            result("Das Boot", 1981, &["The Boat"]),
+        ];
+
+        let ranked = TitleQuery::new("Alien", Some(1979)).rank(&results);
+        assert_eq!(ranked[0].result.year, 1979);
+        assert!(ranked[0].confidence > 0.99);
+        assert!((ranked[1].confidence - WRONG_YEAR_WEIGHT).abs() < 1e-9);
+
+        let ranked = TitleQuery::new("The Boat", None).rank(&results);
+        assert_eq!(ranked[0].matched_title, "The Boat");
+        assert!((ranked[0].confidence - ALTERNATE_TITLE_WEIGHT).abs() < 1e-9);
+    }
+
+    #[test]
+    fn best_match_refuses_ties_and_weak_matches() {
+        let results = vec![result("Alien", 2019, &[]), result("Alien", 1979, &[])];
+
+        let ranked = TitleQuery::new("Alien", Some(1979)).rank(&results);
+        assert!(matches!(best_match(&ranked, 0.9), MatchOutcome::Matched(candidate) if candidate.result.year == 1979));
+
+        let ranked = TitleQuery::new("Alien", None).rank(&results);
+        assert!(matches!(best_match(&ranked, 0.9), MatchOutcome::Ambiguous(_)));
+
+        let ranked = TitleQuery::new("Aliens", Some(1986)).rank(&results);
+        assert!(matches!(best_match(&ranked, 0.9), MatchOutcome::Ambiguous(_)));
+
+        assert!(matches!(best_match(&[], 0.9), MatchOutcome::NotFound));
+    }
+
+    #[test]
+    fn pick_takes_the_best_match_out_of_the_results() {
+        let results = || vec![result("Alien", 2019, &[]), result("Alien", 1979, &[])];
+
+        let picked = TitleQuery::new("Alien", Some(1979)).pick(results(), 0.9);
+        assert!(matches!(picked, TitlePick::Matched(result, _) if result.year == 1979));
+
+        match TitleQuery::new("Alien", None).pick(results(), 0.9) {
+            TitlePick::Ambiguous(candidates) => assert_eq!(candidates.len(), 2),
+            picked => panic!("expected an ambiguous pick, got {:?}", picked),
+        }
+
+        assert!(matches!(TitleQuery::new("Alien", None).pick(vec![], 0.9), TitlePick::NotFound));
+    }
+
+    #[test]
+    fn year_tolerance_allows_off_by_one_release_years() {
+        let result = result("Alien", 1979, &[]);
+
+        let candidate = TitleQuery::new("Alien", Some(1980)).score(&result);
+        assert!((candidate.confidence - NEAR_YEAR_WEIGHT).abs() < 1e-9);
+
+        let candidate = TitleQuery::new("Alien", Some(1980)).with_year_tolerance(0).score(&result);
+        assert!((candidate.confidence - WRONG_YEAR_WEIGHT).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
+
+// `config/ui`
+//
+// firstDayOfWeek: 0
+// shortDateFormat: "MMM D YYYY"
+// showRelativeDates: true
+// movieInfoLanguage: 1
+// id: 1
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UiConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+
+    #[serde(rename = "firstDayOfWeek", skip_serializing_if = "Option::is_none")]
+    pub first_day_of_week: Option<u32>,
+
+    #[serde(rename = "calendarWeekColumnHeader", skip_serializing_if = "Option::is_none")]
+    pub calendar_week_column_header: Option<String>,
+
+    #[serde(rename = "movieRuntimeFormat", skip_serializing_if = "Option::is_none")]
+    pub movie_runtime_format: Option<String>,
+
+    #[serde(rename = "shortDateFormat", skip_serializing_if = "Option::is_none")]
+    pub short_date_format: Option<String>,
+
+    #[serde(rename = "longDateFormat", skip_serializing_if = "Option::is_none")]
+    pub long_date_format: Option<String>,
+
+    #[serde(rename = "timeFormat", skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<String>,
+
+    #[serde(rename = "showRelativeDates", skip_serializing_if = "Option::is_none")]
+    pub show_relative_dates: Option<bool>,
+
+    #[serde(rename = "enableColorImpairedMode", skip_serializing_if = "Option::is_none")]
+    pub enable_color_impaired_mode: Option<bool>,
+
+    #[serde(rename = "movieInfoLanguage", skip_serializing_if = "Option::is_none")]
+    pub movie_info_language: Option<u32>,
+
+    #[serde(rename = "uiLanguage", skip_serializing_if = "Option::is_none")]
+    pub ui_language: Option<u32>,
+
+    // anything newer versions of Radarr add, so updates don't drop it
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
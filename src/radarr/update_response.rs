@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+// version: "3.2.2.5080"
+// branch: "master"
+// releaseDate: "2021-06-02T00:00:00Z"
+// fileName: "Radarr.master.3.2.2.5080.linux-core-x64.tar.gz"
+// url: "https://radarr.servarr.com/v1/update/master/updatefile?..."
+// installed: true
+// installedOn: "2021-06-03T12:00:00Z"
+// installable: false
+// latest: true
+// changes: { new: ["..."], fixed: ["..."] }
+// hash: "..."
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateResponse {
+    pub version: String,
+    pub branch: String,
+
+    #[serde(rename = "releaseDate", with = "super::datetime")]
+    pub release_date: DateTime<Utc>,
+
+    #[serde(rename = "fileName")]
+    pub file_name: Option<String>,
+
+    pub url: Option<String>,
+    pub installed: bool,
+
+    #[serde(rename = "installedOn", default, with = "super::datetime::option")]
+    pub installed_on: Option<DateTime<Utc>>,
+
+    pub installable: bool,
+    pub latest: bool,
+    pub changes: Option<UpdateChanges>,
+    pub hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateChanges {
+    #[serde(default)]
+    pub new: Vec<String>,
+
+    #[serde(default)]
+    pub fixed: Vec<String>,
+}
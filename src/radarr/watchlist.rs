@@ -0,0 +1,197 @@
+use serde::Deserialize;
+
+use std::error::Error;
+use std::str::FromStr;
+
+use super::movie_list::{csv_records, parse_movie_list, MovieListEntry, MovieRef};
+
+// Watchlists exported from other sites, turned into the same entries as a plain movie list.
+//
+// letterboxd: watchlist.csv (or watched.csv, ratings.csv) with `Name` and `Year` columns
+// imdb: a list or watchlist export with `Const` (the IMDb id) and `Title Type` columns. Anything
+//     that isn't a movie is skipped.
+// trakt: a json export (eg: watchlist-movies.json), a list of items with a `movie` object holding
+//     `title`, `year` and `ids`. Shows and episodes are skipped.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListFormat {
+    Plain,
+    Letterboxd,
+    Imdb,
+    Trakt,
+}
+
+impl FromStr for ListFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<ListFormat, String> {
+        match value {
+            "list" => Ok(ListFormat::Plain),
+            "letterboxd" => Ok(ListFormat::Letterboxd),
+            "imdb" => Ok(ListFormat::Imdb),
+            "trakt" => Ok(ListFormat::Trakt),
+            _ => Err(format!("Unknown list format: {}", value)),
+        }
+    }
+}
+
+// parse `data` in the given format. For csv exports an entry's `line` is the line its row starts
+// on, for trakt it's the item's position in the export.
+pub fn parse_watchlist(format: ListFormat, data: &str) -> Result<Vec<MovieListEntry>, Box<dyn Error>> {
+    match format {
+        ListFormat::Plain => Ok(parse_movie_list(data)),
+        ListFormat::Letterboxd => parse_letterboxd(data),
+        ListFormat::Imdb => parse_imdb(data),
+        ListFormat::Trakt => parse_trakt(data),
+    }
+}
+
+fn parse_letterboxd(data: &str) -> Result<Vec<MovieListEntry>, Box<dyn Error>> {
+    let mut records = csv_rows(data).into_iter();
+    let header = records.next().map(|(_, header)| header).unwrap_or_default();
+
+    let name = column(&header, "Name")?;
+    let year = column(&header, "Year")?;
+
+    let entries = records
+        .filter_map(|(line, record)| {
+            let title = record.get(name).filter(|title| !title.is_empty())?.to_owned();
+            let year = record.get(year).and_then(|year| year.parse().ok());
+
+            Some(MovieListEntry { line, movie: MovieRef::Title { title, year } })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+fn parse_imdb(data: &str) -> Result<Vec<MovieListEntry>, Box<dyn Error>> {
+    let mut records = csv_rows(data).into_iter();
+    let header = records.next().map(|(_, header)| header).unwrap_or_default();
+
+    let imdb_id = column(&header, "Const")?;
+    let title_type = column(&header, "Title Type").ok();
+
+    let entries = records
+        .filter(|(_, record)| {
+            // `movie` and `tvMovie` in older exports, `Movie` and `TV Movie` in newer ones
+            title_type.and_then(|index| record.get(index))
+                .is_none_or(|title_type| title_type.to_lowercase().ends_with("movie"))
+        })
+        .filter_map(|(line, record)| {
+            let imdb_id = record.get(imdb_id).filter(|imdb_id| imdb_id.starts_with("tt"))?.to_owned();
+
+            Some(MovieListEntry { line, movie: MovieRef::Imdb(imdb_id) })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+#[derive(Deserialize)]
+struct TraktItem {
+    movie: Option<TraktMovie>,
+}
+
+#[derive(Deserialize)]
+struct TraktMovie {
+    title: Option<String>,
+    year: Option<u32>,
+
+    #[serde(default)]
+    ids: TraktIds,
+}
+
+#[derive(Deserialize, Default)]
+struct TraktIds {
+    tmdb: Option<u32>,
+    imdb: Option<String>,
+}
+
+fn parse_trakt(data: &str) -> Result<Vec<MovieListEntry>, Box<dyn Error>> {
+    let items: Vec<TraktItem> = serde_json::from_str(data)?;
+
+    let entries = items.into_iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            let movie = item.movie?;
+
+            let movie = match (movie.ids.tmdb, movie.ids.imdb, movie.title) {
+                (Some(tmdb_id), _, _) => MovieRef::Tmdb(tmdb_id),
+                (None, Some(imdb_id), _) => MovieRef::Imdb(imdb_id),
+                (None, None, Some(title)) => MovieRef::Title { title, year: movie.year },
+                (None, None, None) => return None,
+            };
+
+            Some(MovieListEntry { line: index + 1, movie })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+// the records of a csv export, without caring which fields were quoted
+fn csv_rows(data: &str) -> Vec<(usize, Vec<String>)> {
+    csv_records(data, ',').into_iter()
+        .map(|(line, record)| (line, record.into_iter().map(|(field, _)| field).collect()))
+        .collect()
+}
+
+fn column(header: &[String], name: &str) -> Result<usize, Box<dyn Error>> {
+    header.iter()
+        .position(|column| column.eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("Missing `{}` column", name).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movies(entries: Vec<MovieListEntry>) -> Vec<(usize, MovieRef)> {
+        entries.into_iter().map(|entry| (entry.line, entry.movie)).collect()
+    }
+
+    fn title(title: &str, year: Option<u32>) -> MovieRef {
+        MovieRef::Title { title: String::from(title), year }
+    }
+
+    #[test]
+    fn parses_letterboxd_watchlist() {
+        let data = include_str!("../../tests/fixtures/letterboxd_watchlist.csv");
+
+        assert_eq!(movies(parse_watchlist(ListFormat::Letterboxd, data).unwrap()), vec![
+            (2, title("Alien", Some(1979))),
+            (3, title("Crouching Tiger, Hidden Dragon", Some(2000))),
+            (4, title("Amélie", Some(2001))),
+            (5, title("Untitled Project", None)),
+        ]);
+    }
+
+    #[test]
+    fn parses_imdb_export_skipping_shows() {
+        let data = include_str!("../../tests/fixtures/imdb_watchlist.csv");
+
+        assert_eq!(movies(parse_watchlist(ListFormat::Imdb, data).unwrap()), vec![
+            (2, MovieRef::Imdb(String::from("tt0133093"))),
+            (4, MovieRef::Imdb(String::from("tt0078748"))),
+            (6, MovieRef::Imdb(String::from("tt0120201"))),
+        ]);
+    }
+
+    #[test]
+    fn parses_trakt_export_preferring_tmdb_ids() {
+        let data = include_str!("../../tests/fixtures/trakt_watchlist.json");
+
+        assert_eq!(movies(parse_watchlist(ListFormat::Trakt, data).unwrap()), vec![
+            (1, MovieRef::Tmdb(603)),
+            (3, MovieRef::Imdb(String::from("tt0078748"))),
+            (4, title("Some Festival Short", Some(2022))),
+        ]);
+    }
+
+    #[test]
+    fn missing_columns_are_an_error() {
+        assert!(parse_watchlist(ListFormat::Letterboxd, "Date,Title\n2023-01-01,Alien\n").is_err());
+        assert!(parse_watchlist(ListFormat::Imdb, "").is_err());
+    }
+}